@@ -0,0 +1,18 @@
+use subxt::rpc::RpcParams;
+
+use crate::{
+    aleph_zero::SessionKeys,
+    connection::{AsConnection, ConnectionApi},
+    pallets::author::AuthorRpc,
+    Result,
+};
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> AuthorRpc for C {
+    type SessionKeys = SessionKeys;
+
+    async fn author_rotate_keys(&self) -> Result<Self::SessionKeys> {
+        self.rpc_call("author_rotateKeys".to_string(), RpcParams::new())
+            .await
+    }
+}
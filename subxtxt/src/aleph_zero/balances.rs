@@ -0,0 +1,105 @@
+use parity_scale_codec::DecodeAll;
+use subxt::{
+    dynamic::DecodedValueThunk,
+    ext::sp_runtime::MultiAddress,
+    tx::{PlainTip, PolkadotExtrinsicParamsBuilder},
+};
+
+use crate::{
+    aleph_zero::{
+        api::{self, runtime_types::pallet_balances::BalanceLock},
+        constant, RuntimeCall,
+    },
+    connection::{AsConnection, ConnectionApi, SignedConnectionApi, TxInfo},
+    pallets::{
+        balances::{BalanceApi, BalanceUserApi, BalanceUserBatchExtApi},
+        utility::UtilityApi,
+    },
+    AccountId, Balance, BlockHash, Result, TxStatus,
+};
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> BalanceApi for C {
+    type BalanceLock = BalanceLock<Balance>;
+
+    fn decode_locks(&self, locks: DecodedValueThunk) -> Result<Vec<Self::BalanceLock>> {
+        // `WeakBoundedVec` is encoded like a `Vec`.
+        Ok(Vec::<BalanceLock<Balance>>::decode_all(
+            &mut locks.encoded(),
+        )?)
+    }
+
+    async fn locks_for_account(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Self::BalanceLock>> {
+        let address = api::storage().balances().locks(&account);
+        Ok(self
+            .get_storage_entry_maybe(&address, at)
+            .await?
+            .map(|locks| locks.0)
+            .unwrap_or_default())
+    }
+
+    async fn total_issuance(&self, at: Option<BlockHash>) -> Result<Balance> {
+        let address = api::storage().balances().total_issuance();
+        self.get_storage_entry(&address, at).await
+    }
+
+    async fn existential_deposit(&self) -> Result<Balance> {
+        constant(self, api::constants().balances().existential_deposit())
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: SignedConnectionApi> BalanceUserApi for S {
+    async fn transfer(&self, dest: AccountId, amount: Balance, status: TxStatus) -> Result<TxInfo> {
+        let tx = api::tx()
+            .balances()
+            .transfer(MultiAddress::Id(dest), amount);
+        self.send_tx(tx, status).await
+    }
+
+    async fn transfer_with_tip(
+        &self,
+        dest: AccountId,
+        amount: Balance,
+        tip: Balance,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        self.send_tx_with_params(
+            api::tx()
+                .balances()
+                .transfer(MultiAddress::Id(dest), amount),
+            PolkadotExtrinsicParamsBuilder::new().tip(PlainTip::new(tip)),
+            status,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: SignedConnectionApi> BalanceUserBatchExtApi for S {
+    async fn batch_transfer(
+        &self,
+        dest: &[AccountId],
+        amount: Balance,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let calls = dest
+            .iter()
+            .map(|dest| transfer_call(dest.clone(), amount))
+            .collect();
+        self.batch_call(calls, status).await
+    }
+}
+
+fn transfer_call(dest: AccountId, amount: Balance) -> RuntimeCall {
+    RuntimeCall::Balances(
+        api::runtime_types::pallet_balances::pallet::Call::transfer {
+            dest: MultiAddress::Id(dest),
+            value: amount,
+        },
+    )
+}
@@ -0,0 +1,157 @@
+pub use api::runtime_types::pallet_contracts::wasm::{Determinism, OwnerInfo};
+use parity_scale_codec::{Compact, Decode, Encode};
+use subxt::{
+    ext::{
+        sp_core::Bytes,
+        sp_runtime::{DispatchError, MultiAddress},
+    },
+    rpc_params,
+};
+
+use crate::{
+    aleph_zero::api,
+    connection::{AsConnection, ConnectionApi, SignedConnectionApi, TxInfo},
+    pallets::contract::{ContractCallArgs, ContractRpc, ContractsApi, ContractsUserApi},
+    AccountId, Balance, BlockHash, CodeHash, Result, TxStatus, Weight,
+};
+
+/// Result of a dry-run contract call, as returned by the `ContractsApi_call` runtime API.
+#[derive(Clone, Debug, Eq, PartialEq, Decode)]
+pub struct ContractExecResult {
+    /// Weight consumed by the call.
+    pub gas_consumed: Weight,
+    /// Weight required to successfully execute the call.
+    pub gas_required: Weight,
+    /// Storage deposit charged or refunded by the call.
+    pub storage_deposit: StorageDeposit,
+    /// Debug output of the contract.
+    pub debug_message: Vec<u8>,
+    /// Return value of the contract, or the reason of its failure.
+    pub result: std::result::Result<ExecReturnValue, DispatchError>,
+}
+
+/// Storage deposit charged or refunded by a contract call.
+#[derive(Clone, Debug, Eq, PartialEq, Decode)]
+pub enum StorageDeposit {
+    /// Amount refunded to the caller.
+    Refund(Balance),
+    /// Amount charged from the caller.
+    Charge(Balance),
+}
+
+/// Value returned by a contract.
+#[derive(Clone, Debug, Eq, PartialEq, Decode)]
+pub struct ExecReturnValue {
+    /// Flags set by the contract, e.g. whether it reverted.
+    pub flags: u32,
+    /// Encoded return value.
+    pub data: Vec<u8>,
+}
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> ContractsApi for C {
+    type OwnerInfo = OwnerInfo;
+
+    async fn get_owner_info(
+        &self,
+        code_hash: CodeHash,
+        at: Option<BlockHash>,
+    ) -> Result<Option<Self::OwnerInfo>> {
+        let address = api::storage().contracts().owner_info_of(code_hash);
+        self.get_storage_entry_maybe(&address, at).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: SignedConnectionApi> ContractsUserApi for S {
+    type Determinism = Determinism;
+
+    async fn upload_code(
+        &self,
+        code: Vec<u8>,
+        storage_limit: Option<Compact<Balance>>,
+        determinism: Self::Determinism,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = api::tx()
+            .contracts()
+            .upload_code(code, storage_limit, determinism);
+        self.send_tx(tx, status).await
+    }
+
+    async fn instantiate(
+        &self,
+        code_hash: CodeHash,
+        balance: Balance,
+        gas_limit: Weight,
+        storage_limit: Option<Compact<Balance>>,
+        data: Vec<u8>,
+        salt: Vec<u8>,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = api::tx().contracts().instantiate(
+            balance,
+            gas_limit.into(),
+            storage_limit,
+            code_hash,
+            data,
+            salt,
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn instantiate_with_code(
+        &self,
+        code: Vec<u8>,
+        balance: Balance,
+        gas_limit: Weight,
+        storage_limit: Option<Compact<Balance>>,
+        data: Vec<u8>,
+        salt: Vec<u8>,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = api::tx().contracts().instantiate_with_code(
+            balance,
+            gas_limit.into(),
+            storage_limit,
+            code,
+            data,
+            salt,
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn call(
+        &self,
+        destination: AccountId,
+        balance: Balance,
+        gas_limit: Weight,
+        storage_limit: Option<Compact<Balance>>,
+        data: Vec<u8>,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = api::tx().contracts().call(
+            MultiAddress::Id(destination),
+            balance,
+            gas_limit.into(),
+            storage_limit,
+            data,
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn remove_code(&self, code_hash: BlockHash, status: TxStatus) -> Result<TxInfo> {
+        let tx = api::tx().contracts().remove_code(code_hash);
+        self.send_tx(tx, status).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> ContractRpc for C {
+    type ContractExecResult = ContractExecResult;
+
+    async fn call_and_get(&self, args: ContractCallArgs) -> Result<Self::ContractExecResult> {
+        let params = rpc_params!["ContractsApi_call", Bytes(args.encode())];
+        self.rpc_call("state_call".to_string(), params).await
+    }
+}
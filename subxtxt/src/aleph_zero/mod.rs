@@ -0,0 +1,84 @@
+//! Implementations of the [`pallets`](crate::pallets) traits for the Aleph Zero runtime.
+//!
+//! With the `aleph-runtime` feature enabled, [`Connection`](crate::connection::Connection),
+//! [`SignedConnection`](crate::connection::SignedConnection) and
+//! [`RootConnection`](crate::connection::RootConnection) implement every trait from
+//! [`pallets`](crate::pallets), with the associated types bound to the runtime types of [`api`].
+//!
+//! The bindings in [`api`] are generated by `subxt` from the Aleph Zero metadata checked in at
+//! `metadata/aleph_zero.scale`, which is refreshed with `make aleph-metadata`. Every call, storage
+//! entry and constant carries a hash of its shape, which is checked against the metadata of the
+//! connected node before use. If the runtime of the node encodes it differently, reads fail with
+//! [`Error::IncompatibleMetadata`] and transactions fail before being signed.
+//!
+//! [`Error::IncompatibleMetadata`]: crate::Error::IncompatibleMetadata
+
+use subxt::constants::ConstantAddress;
+
+use crate::{
+    connection::{AsConnection, RootConnection, SignedConnectionApi, TxInfo},
+    pallets::multisig::Timepoint,
+    Result, TxStatus, Weight,
+};
+
+mod author;
+mod balances;
+mod contract;
+mod multisig;
+mod session;
+mod staking;
+mod system;
+mod transaction_payment;
+mod treasury;
+mod utility;
+mod vesting;
+
+pub use contract::*;
+pub use staking::EraIndex;
+
+/// Bindings generated from the Aleph Zero metadata.
+#[allow(missing_docs, clippy::all)]
+#[subxt::subxt(
+    runtime_metadata_path = "metadata/aleph_zero.scale",
+    derive_for_all_types = "Clone, Eq, PartialEq"
+)]
+pub mod api {}
+
+pub use api::runtime_types::aleph_runtime::{RuntimeCall, SessionKeys};
+
+impl From<Weight> for api::runtime_types::sp_weights::weight_v2::Weight {
+    fn from(weight: Weight) -> Self {
+        Self {
+            ref_time: weight.ref_time,
+            proof_size: weight.proof_size,
+        }
+    }
+}
+
+impl From<Timepoint> for api::runtime_types::pallet_multisig::Timepoint<u32> {
+    fn from(timepoint: Timepoint) -> Self {
+        Self {
+            height: timepoint.height,
+            index: timepoint.index,
+        }
+    }
+}
+
+/// Sends `call` wrapped in `Sudo::sudo`.
+async fn sudo(connection: &RootConnection, call: RuntimeCall, status: TxStatus) -> Result<TxInfo> {
+    connection
+        .send_tx(api::tx().sudo().sudo(call), status)
+        .await
+}
+
+/// Reads a constant of the runtime the connection is connected to.
+fn constant<C: AsConnection, Constant: ConstantAddress>(
+    connection: &C,
+    address: Constant,
+) -> Result<Constant::Target> {
+    Ok(connection
+        .as_connection()
+        .as_client()
+        .constants()
+        .at(&address)?)
+}
@@ -0,0 +1,78 @@
+use crate::{
+    aleph_zero::{api, RuntimeCall},
+    connection::{SignedConnectionApi, TxInfo},
+    pallets::multisig::{CallHash, MultisigThreshold, MultisigUserApi, Timepoint},
+    AccountId, Result, TxStatus, Weight,
+};
+
+#[async_trait::async_trait]
+impl<S: SignedConnectionApi> MultisigUserApi for S {
+    type Call = RuntimeCall;
+
+    async fn as_multi_threshold_1(
+        &self,
+        other_signatories: Vec<AccountId>,
+        call: Self::Call,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = api::tx()
+            .multisig()
+            .as_multi_threshold_1(other_signatories, call);
+        self.send_tx(tx, status).await
+    }
+
+    async fn as_multi(
+        &self,
+        threshold: MultisigThreshold,
+        other_signatories: Vec<AccountId>,
+        timepoint: Option<Timepoint>,
+        max_weight: Weight,
+        call: Self::Call,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = api::tx().multisig().as_multi(
+            threshold,
+            other_signatories,
+            timepoint.map(Into::into),
+            call,
+            max_weight.into(),
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn approve_as_multi(
+        &self,
+        threshold: MultisigThreshold,
+        other_signatories: Vec<AccountId>,
+        timepoint: Option<Timepoint>,
+        max_weight: Weight,
+        call_hash: CallHash,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = api::tx().multisig().approve_as_multi(
+            threshold,
+            other_signatories,
+            timepoint.map(Into::into),
+            call_hash,
+            max_weight.into(),
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn cancel_as_multi(
+        &self,
+        threshold: MultisigThreshold,
+        other_signatories: Vec<AccountId>,
+        timepoint: Timepoint,
+        call_hash: CallHash,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = api::tx().multisig().cancel_as_multi(
+            threshold,
+            other_signatories,
+            timepoint.into(),
+            call_hash,
+        );
+        self.send_tx(tx, status).await
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    aleph_zero::{
+        api::{self, session::events::NewSession},
+        SessionKeys,
+    },
+    blocks::wait_for_state,
+    connection::{AsConnection, ConnectionApi, SignedConnectionApi, TxInfo},
+    pallets::session::{SessionApi, SessionUserApi},
+    AccountId, BlockHash, BlockStatus, Result, TxStatus, WaitTimeout,
+};
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> SessionApi for C {
+    type SessionKeys = SessionKeys;
+    type SessionIndex = u32;
+
+    async fn get_next_session_keys(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<Self::SessionKeys>> {
+        let address = api::storage().session().next_keys(&account);
+        self.get_storage_entry_maybe(&address, at).await
+    }
+
+    async fn get_session(&self, at: Option<BlockHash>) -> Result<Self::SessionIndex> {
+        let address = api::storage().session().current_index();
+        Ok(self
+            .get_storage_entry_maybe(&address, at)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn get_validators(&self, at: Option<BlockHash>) -> Result<Vec<AccountId>> {
+        let address = api::storage().session().validators();
+        Ok(self
+            .get_storage_entry_maybe(&address, at)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn wait_for_session(
+        &self,
+        session: Self::SessionIndex,
+        status: BlockStatus,
+        timeout: WaitTimeout,
+    ) -> Result<()> {
+        wait_for_state(
+            self,
+            |at| async move { self.get_session(at).await.map(|index| index >= session) },
+            |event: &NewSession| event.session_index >= session,
+            status,
+            timeout,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: SignedConnectionApi> SessionUserApi for S {
+    type SessionKeys = SessionKeys;
+
+    async fn set_keys(&self, new_keys: Self::SessionKeys, status: TxStatus) -> Result<TxInfo> {
+        let tx = api::tx().session().set_keys(new_keys, vec![]);
+        self.send_tx(tx, status).await
+    }
+}
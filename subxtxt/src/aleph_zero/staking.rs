@@ -0,0 +1,303 @@
+use futures::TryStreamExt;
+use subxt::{ext::sp_runtime::MultiAddress, storage::StorageKey};
+
+use crate::{
+    aleph_zero::{
+        api::{
+            self,
+            runtime_types::{
+                pallet_staking::{
+                    pallet::pallet::{Call as StakingCall, ConfigOp},
+                    EraRewardPoints, Exposure, RewardDestination, StakingLedger, ValidatorPrefs,
+                },
+                pallet_sudo::pallet::Call as SudoCall,
+                sp_arithmetic::per_things::Perbill,
+            },
+            staking::events::EraPaid,
+        },
+        constant, sudo, RuntimeCall,
+    },
+    blocks::wait_for_state,
+    connection::{AsConnection, ConnectionApi, RootConnection, SignedConnectionApi, TxInfo},
+    pallets::{
+        staking::{
+            eras_stakers_key, eras_stakers_keys, StakingApi, StakingApiExt, StakingRawApi,
+            StakingSudoApi, StakingUserApi,
+        },
+        utility::UtilityApi,
+    },
+    AccountId, Balance, BlockHash, BlockStatus, Result, TxStatus, WaitTimeout,
+    DEFAULT_STORAGE_PAGE_SIZE,
+};
+
+/// An alias for an era index.
+pub type EraIndex = u32;
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> StakingApi for C {
+    type EraIndex = EraIndex;
+    type StakingLedger = StakingLedger;
+    type Exposure = Exposure<AccountId, Balance>;
+    type EraRewardPoints = EraRewardPoints<AccountId>;
+
+    async fn get_active_era(&self, at: Option<BlockHash>) -> Result<Self::EraIndex> {
+        let address = api::storage().staking().active_era();
+        Ok(self.get_storage_entry(&address, at).await?.index)
+    }
+
+    async fn get_current_era(&self, at: Option<BlockHash>) -> Result<Self::EraIndex> {
+        let address = api::storage().staking().current_era();
+        self.get_storage_entry(&address, at).await
+    }
+
+    async fn get_bonded(
+        &self,
+        stash: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<AccountId>> {
+        let address = api::storage().staking().bonded(&stash);
+        self.get_storage_entry_maybe(&address, at).await
+    }
+
+    async fn get_ledger(
+        &self,
+        controller: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Self::StakingLedger> {
+        let address = api::storage().staking().ledger(&controller);
+        self.get_storage_entry(&address, at).await
+    }
+
+    async fn get_payout_for_era(
+        &self,
+        era: Self::EraIndex,
+        at: Option<BlockHash>,
+    ) -> Result<Balance> {
+        let address = api::storage().staking().eras_validator_reward(era);
+        self.get_storage_entry(&address, at).await
+    }
+
+    async fn get_exposure(
+        &self,
+        era: Self::EraIndex,
+        account_id: &AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Self::Exposure> {
+        // An absent entry stands for an empty exposure, like in FRAME staking.
+        let address = api::storage().staking().eras_stakers(era, account_id);
+        Ok(self
+            .get_storage_entry_maybe(&address, at)
+            .await?
+            .unwrap_or(Exposure {
+                total: 0,
+                own: 0,
+                others: vec![],
+            }))
+    }
+
+    async fn get_era_reward_points(
+        &self,
+        era: Self::EraIndex,
+        at: Option<BlockHash>,
+    ) -> Result<Option<Self::EraRewardPoints>> {
+        let address = api::storage().staking().eras_reward_points(era);
+        self.get_storage_entry_maybe(&address, at).await
+    }
+
+    async fn get_minimum_validator_count(&self, at: Option<BlockHash>) -> Result<u32> {
+        let address = api::storage().staking().minimum_validator_count();
+        Ok(self
+            .get_storage_entry_maybe(&address, at)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn get_session_per_era(&self) -> Result<u32> {
+        constant(self, api::constants().staking().sessions_per_era())
+    }
+
+    async fn wait_for_era(
+        &self,
+        era: Self::EraIndex,
+        status: BlockStatus,
+        timeout: WaitTimeout,
+    ) -> Result<()> {
+        // The next era becomes active in the same block in which the previous one is paid out.
+        wait_for_state(
+            self,
+            |at| async move { self.get_active_era(at).await.map(|active| active >= era) },
+            |event: &EraPaid| event.era_index + 1 >= era,
+            status,
+            timeout,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: SignedConnectionApi> StakingUserApi for S {
+    type EraIndex = EraIndex;
+
+    async fn bond(
+        &self,
+        initial_stake: Balance,
+        controller_id: AccountId,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        self.send_tx(bond_call(controller_id, initial_stake), status)
+            .await
+    }
+
+    async fn validate(
+        &self,
+        validator_commission_percentage: u8,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        // `Perbill` counts billionths.
+        let tx = api::tx().staking().validate(ValidatorPrefs {
+            commission: Perbill(validator_commission_percentage as u32 * 10_000_000),
+            blocked: false,
+        });
+        self.send_tx(tx, status).await
+    }
+
+    async fn payout_stakers(
+        &self,
+        stash_account: AccountId,
+        era: Self::EraIndex,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = api::tx().staking().payout_stakers(stash_account, era);
+        self.send_tx(tx, status).await
+    }
+
+    async fn nominate(&self, nominee_account_id: AccountId, status: TxStatus) -> Result<TxInfo> {
+        self.send_tx(nominate_call(nominee_account_id), status)
+            .await
+    }
+
+    async fn chill(&self, status: TxStatus) -> Result<TxInfo> {
+        self.send_tx(api::tx().staking().chill(), status).await
+    }
+
+    async fn bond_extra_stake(&self, extra_stake: Balance, status: TxStatus) -> Result<TxInfo> {
+        let tx = api::tx().staking().bond_extra(extra_stake);
+        self.send_tx(tx, status).await
+    }
+}
+
+#[async_trait::async_trait]
+impl StakingApiExt for RootConnection {
+    async fn batch_bond(
+        &self,
+        accounts: &[(AccountId, AccountId)],
+        stake: Balance,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let calls = accounts
+            .iter()
+            .map(|(stash, controller)| {
+                sudo_as_call(stash.clone(), bond_call(controller.clone(), stake))
+            })
+            .collect();
+        self.batch_call(calls, status).await
+    }
+
+    async fn batch_nominate(
+        &self,
+        nominator_nominee_pairs: &[(AccountId, AccountId)],
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let calls = nominator_nominee_pairs
+            .iter()
+            .map(|(nominator, nominee)| {
+                sudo_as_call(nominator.clone(), nominate_call(nominee.clone()))
+            })
+            .collect();
+        self.batch_call(calls, status).await
+    }
+}
+
+#[async_trait::async_trait]
+impl StakingSudoApi for RootConnection {
+    async fn force_new_era(&self, status: TxStatus) -> Result<TxInfo> {
+        sudo(
+            self,
+            RuntimeCall::Staking(StakingCall::force_new_era),
+            status,
+        )
+        .await
+    }
+
+    async fn set_staking_config(
+        &self,
+        minimal_nominator_bond: Option<Balance>,
+        minimal_validator_bond: Option<Balance>,
+        max_nominators_count: Option<u32>,
+        max_validators_count: Option<u32>,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let call = RuntimeCall::Staking(StakingCall::set_staking_configs {
+            min_nominator_bond: config_op(minimal_nominator_bond),
+            min_validator_bond: config_op(minimal_validator_bond),
+            max_nominator_count: config_op(max_nominators_count),
+            max_validator_count: config_op(max_validators_count),
+            chill_threshold: ConfigOp::Noop,
+            min_commission: ConfigOp::Noop,
+        });
+        sudo(self, call, status).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> StakingRawApi for C {
+    type EraIndex = EraIndex;
+
+    async fn get_stakers_storage_keys(
+        &self,
+        era: Self::EraIndex,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<StorageKey>> {
+        self.storage_keys_paged(eras_stakers_key(era), DEFAULT_STORAGE_PAGE_SIZE, at)
+            .try_collect()
+            .await
+    }
+
+    async fn get_stakers_storage_keys_from_accounts(
+        &self,
+        era: Self::EraIndex,
+        accounts: &[AccountId],
+        _: Option<BlockHash>,
+    ) -> Result<Vec<StorageKey>> {
+        Ok(eras_stakers_keys(era, accounts))
+    }
+}
+
+fn bond_call(controller: AccountId, value: Balance) -> RuntimeCall {
+    RuntimeCall::Staking(StakingCall::bond {
+        controller: MultiAddress::Id(controller),
+        value,
+        payee: RewardDestination::Staked,
+    })
+}
+
+fn nominate_call(nominee: AccountId) -> RuntimeCall {
+    RuntimeCall::Staking(StakingCall::nominate {
+        targets: vec![MultiAddress::Id(nominee)],
+    })
+}
+
+fn sudo_as_call(who: AccountId, call: RuntimeCall) -> RuntimeCall {
+    RuntimeCall::Sudo(SudoCall::sudo_as {
+        who: MultiAddress::Id(who),
+        call: Box::new(call),
+    })
+}
+
+/// `ConfigOp::Set` if `value` is given, `ConfigOp::Noop` otherwise.
+fn config_op<T>(value: Option<T>) -> ConfigOp<T> {
+    match value {
+        Some(value) => ConfigOp::Set(value),
+        None => ConfigOp::Noop,
+    }
+}
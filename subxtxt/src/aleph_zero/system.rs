@@ -0,0 +1,36 @@
+use crate::{
+    aleph_zero::{
+        api::{self, runtime_types::frame_system::pallet::Call as SystemCall},
+        RuntimeCall,
+    },
+    connection::{AsConnection, ConnectionApi, RootConnection, SignedConnectionApi, TxInfo},
+    pallets::system::{SystemApi, SystemSudoApi},
+    AccountId, Balance, BlockHash, Result, TxStatus, Weight,
+};
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> SystemApi for C {
+    async fn get_free_balance(&self, account: AccountId, at: Option<BlockHash>) -> Result<Balance> {
+        let address = api::storage().system().account(&account);
+        Ok(self
+            .get_storage_entry_maybe(&address, at)
+            .await?
+            .map_or(0, |info| info.data.free))
+    }
+}
+
+#[async_trait::async_trait]
+impl SystemSudoApi for RootConnection {
+    async fn set_code(&self, code: Vec<u8>, status: TxStatus) -> Result<TxInfo> {
+        // Runtime upgrade may take more than a block, so its weight is not checked.
+        let tx = api::tx().sudo().sudo_unchecked_weight(
+            RuntimeCall::System(SystemCall::set_code { code }),
+            Weight {
+                ref_time: 0,
+                proof_size: 0,
+            }
+            .into(),
+        );
+        self.send_tx(tx, status).await
+    }
+}
@@ -0,0 +1,17 @@
+use subxt::ext::sp_runtime::FixedU128;
+
+use crate::{
+    aleph_zero::api,
+    connection::{AsConnection, ConnectionApi},
+    pallets::transaction_payment::TransactionPaymentApi,
+    BlockHash, Result,
+};
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> TransactionPaymentApi for C {
+    async fn get_next_fee_multiplier(&self, at: Option<BlockHash>) -> Result<FixedU128> {
+        let address = api::storage().transaction_payment().next_fee_multiplier();
+        let multiplier = self.get_storage_entry(&address, at).await?;
+        Ok(FixedU128::from_inner(multiplier.0))
+    }
+}
@@ -0,0 +1,90 @@
+use parity_scale_codec::Encode;
+use subxt::ext::sp_runtime::{MultiAddress, Perbill};
+
+use crate::{
+    aleph_zero::{api, constant},
+    connection::{AsConnection, ConnectionApi, SignedConnectionApi, TxInfo},
+    pallets::{
+        staking::StakingApi,
+        treasury::{TreasureApiExt, TreasuryApi, TreasuryUserApi},
+    },
+    AccountId, Balance, BlockHash, Result, TxStatus,
+};
+
+/// Identifier of the treasury pallet, from which the treasury account is derived.
+const TREASURY_PALLET_ID: [u8; 8] = *b"a0/trsry";
+/// Number of the smallest token units in a single token.
+const TOKEN: Balance = 1_000_000_000_000;
+/// Amount of tokens minted during a year.
+const YEARLY_INFLATION: Balance = 30_000_000 * TOKEN;
+/// Percentage of minted tokens paid to validators and nominators, the rest goes to the treasury.
+const VALIDATOR_REWARD_PERCENT: u32 = 90;
+/// Target block time.
+const MILLISECS_PER_BLOCK: u64 = 1000;
+/// Number of milliseconds in a year, including a quarter of a leap day.
+const MILLISECS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> TreasuryApi for C {
+    async fn treasury_account(&self) -> Result<AccountId> {
+        // Equivalent of `PalletId::into_account_truncating`.
+        let mut account = [0u8; 32];
+        let encoded = (b"modl", TREASURY_PALLET_ID).encode();
+        account[..encoded.len()].copy_from_slice(&encoded);
+        Ok(AccountId::from(account))
+    }
+
+    async fn proposals_count(&self, at: Option<BlockHash>) -> Result<Option<u32>> {
+        let address = api::storage().treasury().proposal_count();
+        self.get_storage_entry_maybe(&address, at).await
+    }
+
+    async fn approvals(&self, at: Option<BlockHash>) -> Result<Vec<u32>> {
+        let address = api::storage().treasury().approvals();
+        Ok(self
+            .get_storage_entry_maybe(&address, at)
+            .await?
+            .map_or_else(Vec::new, |approvals| approvals.0))
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: SignedConnectionApi> TreasuryUserApi for S {
+    async fn propose_spend(
+        &self,
+        amount: Balance,
+        beneficiary: AccountId,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = api::tx()
+            .treasury()
+            .propose_spend(amount, MultiAddress::Id(beneficiary));
+        self.send_tx(tx, status).await
+    }
+
+    async fn approve(&self, proposal_id: u32, status: TxStatus) -> Result<TxInfo> {
+        let tx = api::tx().treasury().approve_proposal(proposal_id);
+        self.send_tx(tx, status).await
+    }
+
+    async fn reject(&self, proposal_id: u32, status: TxStatus) -> Result<TxInfo> {
+        let tx = api::tx().treasury().reject_proposal(proposal_id);
+        self.send_tx(tx, status).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> TreasureApiExt for C {
+    async fn possible_treasury_payout(&self) -> Result<Balance> {
+        let session_period = constant(self, api::constants().elections().session_period())?;
+        let sessions_per_era = self.get_session_per_era().await?;
+        let millisecs_per_era =
+            MILLISECS_PER_BLOCK * session_period as u64 * sessions_per_era as u64;
+
+        let total_payout =
+            Perbill::from_rational(millisecs_per_era, MILLISECS_PER_YEAR) * YEARLY_INFLATION;
+        let validators_payout = Perbill::from_percent(VALIDATOR_REWARD_PERCENT) * total_payout;
+
+        Ok(total_payout - validators_payout)
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{
+    aleph_zero::{api, RuntimeCall},
+    connection::{SignedConnectionApi, TxInfo},
+    pallets::utility::UtilityApi,
+    Result, TxStatus,
+};
+
+#[async_trait::async_trait]
+impl<S: SignedConnectionApi> UtilityApi for S {
+    type Call = RuntimeCall;
+
+    async fn batch_call(&self, calls: Vec<Self::Call>, status: TxStatus) -> Result<TxInfo> {
+        self.send_tx(api::tx().utility().batch(calls), status).await
+    }
+}
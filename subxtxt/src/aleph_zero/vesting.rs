@@ -0,0 +1,56 @@
+use subxt::ext::sp_runtime::MultiAddress;
+
+use crate::{
+    aleph_zero::api::{self, runtime_types::pallet_vesting::vesting_info::VestingInfo},
+    connection::{AsConnection, ConnectionApi, SignedConnectionApi, TxInfo},
+    pallets::vesting::{VestingApi, VestingUserApi},
+    AccountId, Balance, BlockHash, BlockNumber, Result, TxStatus,
+};
+
+#[async_trait::async_trait]
+impl<C: AsConnection + Sync> VestingApi for C {
+    type VestingInfo = VestingInfo<Balance, BlockNumber>;
+
+    async fn get_vesting(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Self::VestingInfo>> {
+        let address = api::storage().vesting().vesting(&who);
+        Ok(self
+            .get_storage_entry_maybe(&address, at)
+            .await?
+            .map_or_else(Vec::new, |schedules| schedules.0))
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: SignedConnectionApi> VestingUserApi for S {
+    type VestingInfo = VestingInfo<Balance, BlockNumber>;
+
+    async fn vest(&self, status: TxStatus) -> Result<TxInfo> {
+        self.send_tx(api::tx().vesting().vest(), status).await
+    }
+
+    async fn vest_other(&self, status: TxStatus, other: AccountId) -> Result<TxInfo> {
+        let tx = api::tx().vesting().vest_other(MultiAddress::Id(other));
+        self.send_tx(tx, status).await
+    }
+
+    async fn vested_transfer(
+        &self,
+        receiver: AccountId,
+        schedule: Self::VestingInfo,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = api::tx()
+            .vesting()
+            .vested_transfer(MultiAddress::Id(receiver), schedule);
+        self.send_tx(tx, status).await
+    }
+
+    async fn merge_schedules(&self, idx1: u32, idx2: u32, status: TxStatus) -> Result<TxInfo> {
+        let tx = api::tx().vesting().merge_schedules(idx1, idx2);
+        self.send_tx(tx, status).await
+    }
+}
@@ -0,0 +1,388 @@
+use std::time::Duration;
+
+use futures::{
+    future,
+    stream::{self, BoxStream},
+    StreamExt, TryStreamExt,
+};
+use parity_scale_codec::{Compact, Decode};
+use subxt::{
+    dynamic::DecodedValue,
+    events::{Events, Phase, StaticEvent},
+    ext::{scale_value::scale::decode_as_type, sp_runtime::traits::Header},
+    rpc::Subscription,
+    Config, Metadata, PolkadotConfig,
+};
+
+use crate::{
+    connection::Connection, offline::SIGNED_MASK, BlockHash, BlockNumber, ChainConfig, Error,
+    Result,
+};
+
+/// Blocks of the chain, as returned by
+/// [`ConnectionApi::subscribe_best_blocks`](crate::connection::ConnectionApi::subscribe_best_blocks)
+/// and
+/// [`ConnectionApi::subscribe_finalized_blocks`](crate::connection::ConnectionApi::subscribe_finalized_blocks).
+pub type BlockStream<C = PolkadotConfig> = BoxStream<'static, Result<Block<C>>>;
+
+/// Events of a single type, as returned by
+/// [`ConnectionApi::subscribe_events`](crate::connection::ConnectionApi::subscribe_events).
+pub type EventStream<E> = BoxStream<'static, Result<ObservedEvent<E>>>;
+
+/// Which blocks to follow when subscribing to the chain.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BlockStatus {
+    /// Blocks announced as the best ones. They may be reverted by a reorg.
+    Best,
+    /// Finalized blocks.
+    Finalized,
+}
+
+/// How long to wait for something to happen on chain, e.g. in
+/// [`ConnectionApi::wait_for_event`](crate::connection::ConnectionApi::wait_for_event).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum WaitTimeout {
+    /// At most this many blocks, counting from the first block observed.
+    Blocks(BlockNumber),
+    /// At most this long.
+    Duration(Duration),
+}
+
+/// A block of the chain, together with its extrinsics and events.
+pub struct Block<C: Config = PolkadotConfig> {
+    /// Number of the block.
+    pub number: BlockNumber,
+    /// Hash of the block.
+    pub hash: BlockHash,
+    /// Hash of the parent block.
+    pub parent_hash: BlockHash,
+    /// Extrinsics of the block, in the order of execution.
+    pub extrinsics: Vec<BlockExtrinsic>,
+    /// Events emitted in the block.
+    pub events: Events<C>,
+}
+
+/// An extrinsic of a [`Block`], decoded according to the runtime metadata.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockExtrinsic {
+    /// Index of the extrinsic within the block.
+    pub index: u32,
+    /// Address of the account that signed the extrinsic, `None` for unsigned extrinsics.
+    pub signer: Option<DecodedValue>,
+    /// The call, as a variant named after the pallet, wrapping a variant named after the call.
+    pub call: DecodedValue,
+    /// Raw extrinsic.
+    pub bytes: Vec<u8>,
+}
+
+/// An event emitted in a block of the chain.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObservedEvent<E> {
+    /// The event itself.
+    pub event: E,
+    /// Hash of the block in which the event was emitted.
+    pub block_hash: BlockHash,
+    /// Number of the block in which the event was emitted.
+    pub block_number: BlockNumber,
+    /// Phase of the block execution in which the event was emitted.
+    pub phase: Phase,
+    /// Index of the extrinsic that emitted the event, `None` for events emitted during block
+    /// initialization or finalization.
+    pub extrinsic_index: Option<u32>,
+}
+
+impl<C: ChainConfig> Block<C> {
+    /// Fetches the block with `hash` together with its contents.
+    pub(crate) async fn fetch(connection: &Connection<C>, hash: BlockHash) -> Result<Self> {
+        let client = connection.as_client();
+        connection
+            .retry_request(|| async move {
+                let block = client.blocks().at(Some(hash)).await?;
+                let metadata = client.metadata();
+                let extrinsics = block
+                    .body()
+                    .await?
+                    .extrinsics()
+                    .map(|extrinsic| {
+                        BlockExtrinsic::decode(extrinsic.index(), extrinsic.bytes(), &metadata)
+                    })
+                    .collect::<Result<_>>()?;
+                let events = block.events().await?;
+                let header = block.header();
+
+                Ok(Self {
+                    number: *header.number(),
+                    hash,
+                    parent_hash: *header.parent_hash(),
+                    extrinsics,
+                    events,
+                })
+            })
+            .await
+    }
+}
+
+impl<C: Config> Block<C> {
+    /// Returns all events of type `E` emitted in the block, in the order of emission.
+    pub fn find_events<E: StaticEvent>(&self) -> Result<Vec<ObservedEvent<E>>> {
+        let mut events = vec![];
+        for details in self.events.iter() {
+            let details = details?;
+            if let Some(event) = details.as_event::<E>()? {
+                let phase = details.phase();
+                events.push(ObservedEvent {
+                    event,
+                    block_hash: self.hash,
+                    block_number: self.number,
+                    extrinsic_index: match phase {
+                        Phase::ApplyExtrinsic(index) => Some(index),
+                        _ => None,
+                    },
+                    phase,
+                });
+            }
+        }
+        Ok(events)
+    }
+}
+
+impl BlockExtrinsic {
+    fn decode(index: u32, bytes: &[u8], metadata: &Metadata) -> Result<Self> {
+        let mut cursor = bytes;
+        // Depending on the node, extrinsics may be served together with their length.
+        if let Ok(Compact(length)) = Compact::<u32>::decode(&mut cursor) {
+            if length as usize != cursor.len() {
+                cursor = bytes;
+            }
+        }
+
+        let version = u8::decode(&mut cursor)?;
+        let signer = match version & SIGNED_MASK {
+            0 => None,
+            _ => {
+                let signer = decode_param(&mut cursor, "Address", metadata)?;
+                decode_param(&mut cursor, "Signature", metadata)?;
+                decode_param(&mut cursor, "Extra", metadata)?;
+                Some(signer)
+            }
+        };
+        let call = decode_param(&mut cursor, "Call", metadata)?;
+
+        Ok(Self {
+            index,
+            signer,
+            call,
+            bytes: bytes.to_vec(),
+        })
+    }
+}
+
+/// Decodes a part of an extrinsic, typed as the `param` type parameter of the extrinsic type in
+/// the runtime metadata, e.g. `Address` or `Call`.
+fn decode_param(cursor: &mut &[u8], param: &str, metadata: &Metadata) -> Result<DecodedValue> {
+    let extrinsic_type = metadata.runtime_metadata().extrinsic.ty.id();
+    let type_id = metadata
+        .types()
+        .resolve(extrinsic_type)
+        .and_then(|ty| {
+            ty.type_params()
+                .iter()
+                .find(|type_param| type_param.name() == param)
+        })
+        .and_then(|type_param| type_param.ty())
+        .ok_or_else(|| {
+            parity_scale_codec::Error::from("extrinsic type is missing in the runtime metadata")
+        })?;
+
+    decode_as_type(cursor, type_id.id(), metadata.types()).map_err(|_| {
+        parity_scale_codec::Error::from("extrinsic does not match the runtime metadata").into()
+    })
+}
+
+/// Blocks announced as best by the node. Blocks skipped by the node, e.g. due to a reorg, are not
+/// backfilled.
+pub(crate) fn best_blocks<C: ChainConfig>(
+    connection: Connection<C>,
+    headers: Subscription<C::Header>,
+) -> BlockStream<C> {
+    headers
+        .map_err(Error::from)
+        .and_then(move |header| {
+            let connection = connection.clone();
+            async move { Block::fetch(&connection, header.hash()).await }
+        })
+        .boxed()
+}
+
+/// Blocks announced as finalized by the node. The node may skip some of the finalized blocks, e.g.
+/// when many of them are finalized at once. These are fetched as well, so that all blocks
+/// following the first one are streamed in order, without gaps.
+pub(crate) fn finalized_blocks<C: ChainConfig>(
+    connection: Connection<C>,
+    headers: Subscription<C::Header>,
+) -> BlockStream<C> {
+    let hashes = {
+        let connection = connection.clone();
+        stream::try_unfold((headers, None), move |(mut headers, last)| {
+            let connection = connection.clone();
+            async move {
+                let header = match headers.try_next().await? {
+                    Some(header) => header,
+                    None => return Ok(None),
+                };
+                let number = *header.number();
+                let first = match last {
+                    // The node may announce the last finalized block again, e.g. after reconnecting.
+                    Some(last) if number <= last => {
+                        return Ok(Some((vec![], (headers, Some(last)))))
+                    }
+                    Some(last) => last + 1,
+                    None => number,
+                };
+
+                let mut hashes = vec![];
+                for skipped in first..number {
+                    hashes.push(block_hash(&connection, skipped).await?);
+                }
+                hashes.push(header.hash());
+
+                Ok::<_, Error>(Some((hashes, (headers, Some(number)))))
+            }
+        })
+    };
+
+    hashes
+        .map_ok(|hashes| stream::iter(hashes.into_iter().map(Ok)))
+        .try_flatten()
+        .and_then(move |hash| {
+            let connection = connection.clone();
+            async move { Block::fetch(&connection, hash).await }
+        })
+        .boxed()
+}
+
+/// Hash of the canonical block with `number`.
+async fn block_hash<C: ChainConfig>(
+    connection: &Connection<C>,
+    number: BlockNumber,
+) -> Result<BlockHash> {
+    connection
+        .retry_request(|| async move {
+            connection
+                .as_client()
+                .rpc()
+                .block_hash(Some(number.into()))
+                .await
+                .map_err(Error::from)
+        })
+        .await?
+        .ok_or_else(|| Error::UnexpectedResponse(format!("No block number {number}")))
+}
+
+/// Hash of the latest block with `status`, as expected by storage reads: `None` for the best block.
+#[cfg(any(feature = "aleph-runtime", feature = "substrate-node"))]
+async fn latest_block<C: ChainConfig, X: crate::connection::AsConnection<C> + Sync>(
+    connection: &X,
+    status: BlockStatus,
+) -> Result<Option<BlockHash>> {
+    match status {
+        BlockStatus::Best => Ok(None),
+        BlockStatus::Finalized => Ok(Some(
+            connection
+                .as_connection()
+                .as_client()
+                .rpc()
+                .finalized_head()
+                .await?,
+        )),
+    }
+}
+
+/// Waits until the state at the latest block with `status` satisfies `reached`, which is signalled
+/// by an event of type `E` matching `predicate`.
+///
+/// Blocks are subscribed to before the state is checked, so that an event emitted in between is
+/// not missed.
+#[cfg(any(feature = "aleph-runtime", feature = "substrate-node"))]
+pub(crate) async fn wait_for_state<C, X, E, P, R, Fut>(
+    connection: &X,
+    reached: R,
+    predicate: P,
+    status: BlockStatus,
+    timeout: WaitTimeout,
+) -> Result<()>
+where
+    C: ChainConfig,
+    X: crate::connection::AsConnection<C> + Sync,
+    E: StaticEvent + Send + 'static,
+    P: Fn(&E) -> bool + Send + Sync,
+    R: FnOnce(Option<BlockHash>) -> Fut + Send,
+    Fut: std::future::Future<Output = Result<bool>> + Send,
+{
+    let blocks = crate::connection::subscribe_blocks(connection.as_connection(), status).await?;
+    if reached(latest_block(connection, status).await?).await? {
+        return Ok(());
+    }
+    find_event(blocks, predicate, timeout).await?;
+    Ok(())
+}
+
+/// Returns the first event of type `E` matching `predicate`, emitted in `blocks`.
+pub(crate) async fn find_event<C, E, P>(
+    mut blocks: BlockStream<C>,
+    predicate: P,
+    timeout: WaitTimeout,
+) -> Result<ObservedEvent<E>>
+where
+    C: ChainConfig,
+    E: StaticEvent + Send + 'static,
+    P: Fn(&E) -> bool + Send + Sync,
+{
+    let timed_out = || {
+        Error::Timeout(format!(
+            "no matching {}::{} event within {:?}",
+            E::PALLET,
+            E::EVENT,
+            timeout
+        ))
+    };
+
+    let wait = async {
+        let mut observed_blocks = 0;
+        while let Some(block) = blocks.try_next().await? {
+            let event = block
+                .find_events::<E>()?
+                .into_iter()
+                .find(|observed| predicate(&observed.event));
+            if let Some(event) = event {
+                return Ok(event);
+            }
+
+            observed_blocks += 1;
+            if matches!(timeout, WaitTimeout::Blocks(max) if observed_blocks >= max) {
+                return Err(timed_out());
+            }
+        }
+        Err(Error::UnexpectedResponse(
+            "Block subscription ended".to_string(),
+        ))
+    };
+
+    match timeout {
+        WaitTimeout::Blocks(_) => wait.await,
+        WaitTimeout::Duration(duration) => tokio::time::timeout(duration, wait)
+            .await
+            .unwrap_or_else(|_| Err(timed_out())),
+    }
+}
+
+/// Events of type `E` emitted in `blocks`.
+pub(crate) fn events<C: ChainConfig, E: StaticEvent + Send + 'static>(
+    blocks: BlockStream<C>,
+) -> EventStream<E> {
+    blocks
+        .and_then(|block| future::ready(block.find_events::<E>()))
+        .map_ok(|events| stream::iter(events.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+}
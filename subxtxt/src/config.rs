@@ -0,0 +1,89 @@
+use std::fmt::Debug;
+
+use subxt::{
+    ext::sp_runtime::traits::{IdentifyAccount, Verify},
+    tx::{BaseExtrinsicParamsBuilder, Era, ExtrinsicParams},
+    Config,
+};
+
+use crate::{BlockHash, BlockNumber};
+
+/// A [`Config`] of a chain that connections of this crate can talk to.
+///
+/// It is implemented for every config that uses [`BlockHash`] as its hash type, [`BlockNumber`]
+/// and `u32` nonces, e.g. [`PolkadotConfig`](subxt::PolkadotConfig). Signed extensions, the
+/// hashing algorithm, the account types and the signature can be chosen freely. Key pairs, e.g.
+/// [`KeyPair::new`](crate::KeyPair::new) or [`KeyPair::ed25519`](crate::KeyPair::ed25519),
+/// additionally require the signature of the chain to support their scheme.
+///
+/// # Examples
+/// ```ignore
+///     let connection = ConnectionBuilder::<MyConfig>::new(&["ws://127.0.0.1:9943"])
+///         .build()
+///         .await?;
+/// ```
+pub trait ChainConfig:
+    Config<
+        Index = u32,
+        BlockNumber = BlockNumber,
+        Hash = BlockHash,
+        ExtrinsicParams = <Self as ChainConfig>::TxExtrinsicParams,
+    > + Send
+    + Sync
+{
+    /// The same type as [`Config::ExtrinsicParams`], bound so that its other params are
+    /// [`TxParams`](Self::TxParams).
+    type TxExtrinsicParams: ExtrinsicParams<
+        u32,
+        BlockHash,
+        OtherParams = <Self as ChainConfig>::TxParams,
+    >;
+
+    /// Additional parameters of a transaction, e.g. tip. For
+    /// [`PolkadotConfig`](subxt::PolkadotConfig) it is
+    /// [`PolkadotExtrinsicParamsBuilder`](subxt::tx::PolkadotExtrinsicParamsBuilder).
+    type TxParams: Debug + Default + Send + Sync;
+
+    /// Returns the address under which `account` signs extrinsics.
+    fn account_address(account: &Self::AccountId) -> Self::Address;
+
+    /// Checks that `signature` of `payload` was made by `account`.
+    fn verify(signature: &Self::Signature, payload: &[u8], account: &Self::AccountId) -> bool;
+}
+
+impl<C> ChainConfig for C
+where
+    C: Config<Index = u32, BlockNumber = BlockNumber, Hash = BlockHash> + Send + Sync,
+    <C::ExtrinsicParams as ExtrinsicParams<u32, BlockHash>>::OtherParams: Debug + Send + Sync,
+    C::Signature: Verify,
+    <C::Signature as Verify>::Signer: IdentifyAccount<AccountId = C::AccountId>,
+    C::AccountId: Into<C::Address>,
+{
+    type TxExtrinsicParams = C::ExtrinsicParams;
+    type TxParams = <C::ExtrinsicParams as ExtrinsicParams<u32, BlockHash>>::OtherParams;
+
+    fn account_address(account: &Self::AccountId) -> Self::Address {
+        account.clone().into()
+    }
+
+    fn verify(signature: &Self::Signature, payload: &[u8], account: &Self::AccountId) -> bool {
+        signature.verify(payload, account)
+    }
+}
+
+/// Transaction params that can make a transaction mortal, i.e. valid only within a limited range
+/// of blocks. Implemented for the params of [`PolkadotConfig`](subxt::PolkadotConfig) and
+/// [`SubstrateConfig`](subxt::SubstrateConfig).
+pub trait MortalTxParams {
+    /// Makes the transaction valid only within `era`, which starts at the block with hash
+    /// `checkpoint`.
+    fn mortal(self, era: Era, checkpoint: BlockHash) -> Self;
+}
+
+impl<C: Config<Hash = BlockHash>, Tip: Default> MortalTxParams
+    for BaseExtrinsicParamsBuilder<C, Tip>
+{
+    fn mortal(self, era: Era, checkpoint: BlockHash) -> Self {
+        self.era(era, checkpoint)
+    }
+}
@@ -1,99 +1,206 @@
 //! Module introducing few types of connections to the chain.
 
-use std::{thread::sleep, time::Duration};
+use std::{
+    collections::HashMap, fmt, future::Future, marker::PhantomData, sync::Arc, time::Duration,
+};
 
-use anyhow::anyhow;
-use log::info;
+use futures::{
+    stream::{self, BoxStream},
+    FutureExt, Stream, StreamExt, TryStreamExt,
+};
+use log::{info, warn};
 use parity_scale_codec::Decode;
 use serde::{Deserialize, Serialize};
 use subxt::{
     blocks::ExtrinsicEvents,
-    ext::sp_core::Bytes,
-    metadata::DecodeWithMetadata,
-    rpc::RpcParams,
-    storage::{address::Yes, StaticStorageAddress, StorageAddress},
-    tx::{PolkadotExtrinsicParamsBuilder, TxPayload},
-    OnlineClient, PolkadotConfig, SubstrateConfig,
+    dynamic::{self, DecodedValue, DecodedValueThunk, Value},
+    error::RpcError,
+    events::StaticEvent,
+    ext::{sp_core::Bytes, sp_runtime::traits::Header},
+    metadata::{DecodeStaticType, DecodeWithMetadata},
+    rpc::{rpc_params, RpcClientT, RpcParams},
+    storage::{address::Yes, StaticStorageAddress, StorageAddress, StorageKey},
+    tx::{Era, SubmittableExtrinsic, TxInBlock, TxPayload, TxProgress, TxStatus as SubxtTxStatus},
+    Config, Metadata, OnlineClient, PolkadotConfig,
 };
+use tokio::time::Instant;
 
-use crate::{key_pair::KeyPair, AccountId, BlockHash, TxHash, TxStatus};
+use crate::{
+    blocks::{
+        best_blocks, events, finalized_blocks, find_event, BlockStatus, BlockStream, EventStream,
+        ObservedEvent, WaitTimeout,
+    },
+    estimate::{dry_run, estimate_fee, DryRunOutcome, FeeEstimate},
+    nonce::NonceManager,
+    offline::UnsignedTx,
+    rpc::{
+        ws_client, EndpointPool, LoadBalancing, PrimaryEndpoint, ReconnectEvent, ReconnectHook,
+        ReconnectingRpcClient,
+    },
+    storage::{
+        decode_value, split_storage_changes, storage_key, value_type, StorageChanges,
+        StorageMapDecoder, StorageMapEntry,
+    },
+    AccountId, BlockHash, BlockNumber, ChainConfig, Error, MortalTxParams, Result, RetryPolicy,
+    Signer, TxHash, TxStatus,
+};
 
 /// Capable of communicating with a live Aleph chain.
-#[derive(Clone)]
-pub struct Connection {
-    client: OnlineClient<PolkadotConfig>,
+///
+/// By default, it talks to a chain described by [`PolkadotConfig`]. Use [`ConnectionBuilder::new`]
+/// to connect to a chain with another [`ChainConfig`].
+pub struct Connection<C: Config = PolkadotConfig> {
+    client: OnlineClient<C>,
+    /// Client for submitting and watching transactions. With many nodes, it is bound to the
+    /// primary one, so that a transaction is watched by the node that knows its block.
+    tx_client: OnlineClient<C>,
+    request_retry_policy: RetryPolicy,
+    tx_timeouts: TxTimeouts,
+}
+
+/// Configures and creates a [`Connection`].
+///
+/// # Examples
+/// ```ignore
+///     let connection = Connection::builder("ws://127.0.0.1:9943")
+///         .retry_policy(RetryPolicy::exponential(Duration::from_millis(100), Duration::from_secs(10)))
+///         .request_retry_policy(RetryPolicy::fixed(Duration::from_millis(500)).with_max_retries(3))
+///         .tx_timeouts(TxTimeouts { in_block: Some(Duration::from_secs(60)), finalized: None })
+///         .auto_reconnect()
+///         .on_reconnect(|event| println!("{event:?}"))
+///         .build()
+///         .await?;
+/// ```
+pub struct ConnectionBuilder<C: Config = PolkadotConfig> {
+    addresses: Vec<String>,
+    retry_policy: RetryPolicy,
+    request_retry_policy: RetryPolicy,
+    tx_timeouts: TxTimeouts,
+    auto_reconnect: bool,
+    reconnect_hooks: Vec<ReconnectHook>,
+    load_balancing: LoadBalancing,
+    _config: PhantomData<C>,
 }
 
 /// Any connection that is signed by some key.
-#[derive(Clone)]
-pub struct SignedConnection {
-    connection: Connection,
-    signer: KeyPair,
+pub struct SignedConnection<C: Config = PolkadotConfig> {
+    connection: Connection<C>,
+    signer: Arc<dyn Signer<C>>,
+    nonce_manager: Arc<NonceManager>,
 }
 
 /// Specific connection that is signed by the sudo key.
-#[derive(Clone)]
-pub struct RootConnection {
-    connection: SignedConnection,
+pub struct RootConnection<C: Config = PolkadotConfig> {
+    connection: SignedConnection<C>,
+}
+
+impl<C: Config> Clone for Connection<C> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            tx_client: self.tx_client.clone(),
+            request_retry_policy: self.request_retry_policy.clone(),
+            tx_timeouts: self.tx_timeouts,
+        }
+    }
+}
+
+impl<C: Config> Clone for ConnectionBuilder<C> {
+    fn clone(&self) -> Self {
+        Self {
+            addresses: self.addresses.clone(),
+            retry_policy: self.retry_policy.clone(),
+            request_retry_policy: self.request_retry_policy.clone(),
+            tx_timeouts: self.tx_timeouts,
+            auto_reconnect: self.auto_reconnect,
+            reconnect_hooks: self.reconnect_hooks.clone(),
+            load_balancing: self.load_balancing,
+            _config: PhantomData,
+        }
+    }
+}
+
+impl<C: ChainConfig> Clone for SignedConnection<C> {
+    fn clone(&self) -> Self {
+        Self {
+            connection: self.connection.clone(),
+            signer: self.signer.clone(),
+            nonce_manager: self.nonce_manager.clone(),
+        }
+    }
+}
+
+impl<C: ChainConfig> Clone for RootConnection<C> {
+    fn clone(&self) -> Self {
+        Self {
+            connection: self.connection.clone(),
+        }
+    }
 }
 
 /// Castability to a plain connection.
-pub trait AsConnection {
+pub trait AsConnection<C: Config = PolkadotConfig> {
     /// Allows cast to [`Connection`] reference
-    fn as_connection(&self) -> &Connection;
+    fn as_connection(&self) -> &Connection<C>;
 }
 
 /// Castability to a signed connection.
-pub trait AsSigned {
+pub trait AsSigned<C: Config = PolkadotConfig>: AsConnection<C> {
     /// Allows cast to [`SignedConnection`] reference
-    fn as_signed(&self) -> &SignedConnection;
+    fn as_signed(&self) -> &SignedConnection<C>;
 }
 
-impl AsConnection for Connection {
-    fn as_connection(&self) -> &Connection {
+impl<C: Config> AsConnection<C> for Connection<C> {
+    fn as_connection(&self) -> &Connection<C> {
         self
     }
 }
 
-impl<S: AsSigned> AsConnection for S {
-    fn as_connection(&self) -> &Connection {
-        &self.as_signed().connection
+impl<C: Config> AsConnection<C> for SignedConnection<C> {
+    fn as_connection(&self) -> &Connection<C> {
+        &self.connection
+    }
+}
+
+impl<C: Config> AsConnection<C> for RootConnection<C> {
+    fn as_connection(&self) -> &Connection<C> {
+        &self.connection.connection
     }
 }
 
-impl AsSigned for SignedConnection {
-    fn as_signed(&self) -> &SignedConnection {
+impl<C: Config> AsSigned<C> for SignedConnection<C> {
+    fn as_signed(&self) -> &SignedConnection<C> {
         self
     }
 }
 
-impl AsSigned for RootConnection {
-    fn as_signed(&self) -> &SignedConnection {
+impl<C: Config> AsSigned<C> for RootConnection<C> {
+    fn as_signed(&self) -> &SignedConnection<C> {
         &self.connection
     }
 }
 
 /// Any connection should be able to request storage and submit RPC calls
 #[async_trait::async_trait]
-pub trait ConnectionApi: Sync {
+pub trait ConnectionApi<C: ChainConfig = PolkadotConfig>: Sync {
     /// Retrieves a decoded storage value stored under given key.
     ///
-    /// # Panic
-    /// This method `panic`s, in case storage key is invalid, or in case value cannot be decoded,
-    /// or there is no such value
+    /// # Errors
+    /// Fails with [`Error::MissingStorage`] in case there is no such value, or with other [`Error`]
+    /// in case storage key is invalid, or in case value cannot be decoded.
     /// * `addrs` - represents a storage key, see [more info about keys](https://docs.substrate.io/fundamentals/state-transitions-and-storage/#querying-storage)
     /// * `at` - optional block hash to query state from
     async fn get_storage_entry<T: DecodeWithMetadata + Sync, Defaultable: Sync, Iterable: Sync>(
         &self,
         addrs: &StaticStorageAddress<T, Yes, Defaultable, Iterable>,
         at: Option<BlockHash>,
-    ) -> T::Target;
+    ) -> Result<T::Target>;
 
     /// Retrieves a decoded storage value stored under given key.
     ///
-    /// # Panic
-    /// This method `panic`s, in case storage key is invalid, or in case value cannot be decoded,
-    /// but does _not_ `panic` if there is no such value
+    /// # Errors
+    /// Fails in case storage key is invalid, or in case value cannot be decoded, but returns
+    /// `Ok(None)` if there is no such value
     /// * `addrs` - represents a storage key, see [more info about keys](https://docs.substrate.io/fundamentals/state-transitions-and-storage/#querying-storage)
     /// * `at` - optional block hash to query state from
     ///
@@ -110,7 +217,235 @@ pub trait ConnectionApi: Sync {
         &self,
         addrs: &StaticStorageAddress<T, Yes, Defaultable, Iterable>,
         at: Option<BlockHash>,
-    ) -> Option<T::Target>;
+    ) -> Result<Option<T::Target>>;
+
+    /// Retrieves decoded storage values stored under many keys, all in a single request.
+    ///
+    /// # Errors
+    /// Fails in case any storage key is invalid, or in case any value cannot be decoded.
+    /// * `addrs` - storage keys, see [`Self::get_storage_entry`]
+    /// * `at` - optional block hash to query state from
+    ///
+    /// # Returns
+    /// Values in the order of `addrs`, `None` for keys with no value. Repeated addresses get the
+    /// same value.
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let addrs = accounts
+    ///         .iter()
+    ///         .map(|account| api::storage().balances().locks(account))
+    ///         .collect::<Vec<_>>();
+    ///     let locks = conn.get_storage_entries(&addrs, None).await?;
+    /// ```
+    async fn get_storage_entries<Address: StorageAddress<IsFetchable = Yes> + Sync>(
+        &self,
+        addrs: &[Address],
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Option<<Address::Target as DecodeWithMetadata>::Target>>>;
+
+    /// Subscribes to changes of storage values stored under given keys.
+    ///
+    /// # Errors
+    /// Fails in case any storage key is invalid, or in case the node rejects the subscription.
+    /// * `addrs` - storage keys, see [`Self::get_storage_entry`]
+    ///
+    /// # Returns
+    /// A stream of changes for each of `addrs`, in the same order. The first item of every stream
+    /// is the value at the best block at the time of subscribing. All the streams share a single
+    /// subscription, which is read as the streams are polled.
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let addrs = api::storage().staking().ledger(&controller);
+    ///     let mut changes = conn.subscribe_storage(&[addrs]).await?.remove(0);
+    ///     while let Some((block_hash, ledger)) = changes.try_next().await? {
+    ///         println!("{:?}: {:?}", block_hash, ledger);
+    ///     }
+    /// ```
+    async fn subscribe_storage<Address>(
+        &self,
+        addrs: &[Address],
+    ) -> Result<Vec<StorageChanges<<Address::Target as DecodeWithMetadata>::Target>>>
+    where
+        Address: StorageAddress<IsFetchable = Yes> + Sync,
+        Address::Target: 'static,
+        <Address::Target as DecodeWithMetadata>::Target: Send;
+
+    /// Subscribes to blocks announced by the node as the best ones, i.e. heads of the longest
+    /// chain. After a reorg, blocks of the new chain that were not announced are not streamed.
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let mut blocks = conn.subscribe_best_blocks().await?;
+    ///     while let Some(block) = blocks.try_next().await? {
+    ///         println!("#{} {:?}: {} extrinsics", block.number, block.hash, block.extrinsics.len());
+    ///     }
+    /// ```
+    async fn subscribe_best_blocks(&self) -> Result<BlockStream<C>>;
+
+    /// Subscribes to finalized blocks. All blocks starting from the first one are streamed in
+    /// order, even if the node finalizes many of them at once and announces only the last one.
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let mut blocks = conn.subscribe_finalized_blocks().await?;
+    ///     while let Some(block) = blocks.try_next().await? {
+    ///         index(block.number, block.events)?;
+    ///     }
+    /// ```
+    async fn subscribe_finalized_blocks(&self) -> Result<BlockStream<C>>;
+
+    /// Subscribes to events of type `E`, emitted in blocks with the given status. Events are
+    /// streamed in the order of blocks and, within a block, in the order of emission.
+    /// * `status` - whether to follow best or finalized blocks, see
+    /// [`Self::subscribe_best_blocks`] and [`Self::subscribe_finalized_blocks`]
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let mut rewards = conn
+    ///         .subscribe_events::<api::staking::events::Rewarded>(BlockStatus::Finalized)
+    ///         .await?;
+    ///     while let Some(reward) = rewards.try_next().await? {
+    ///         println!("#{}: {:?}", reward.block_number, reward.event);
+    ///     }
+    /// ```
+    async fn subscribe_events<E: StaticEvent + Send + 'static>(
+        &self,
+        status: BlockStatus,
+    ) -> Result<EventStream<E>>;
+
+    /// Waits for the first event of type `E` that matches `predicate`, emitted in blocks with the
+    /// given status. Only blocks announced after calling this method are checked.
+    /// * `predicate` - which events to wait for
+    /// * `status` - whether to follow best or finalized blocks
+    /// * `timeout` - how long to wait at most
+    ///
+    /// # Errors
+    /// Fails with [`Error::Timeout`] in case no matching event was emitted in time.
+    ///
+    /// # Returns
+    /// The matching event, together with the block it was emitted in.
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let awarded = conn
+    ///         .wait_for_event(
+    ///             |event: &api::treasury::events::Awarded| event.proposal_index == proposal_id,
+    ///             BlockStatus::Finalized,
+    ///             WaitTimeout::Blocks(20),
+    ///         )
+    ///         .await?;
+    /// ```
+    async fn wait_for_event<E, P>(
+        &self,
+        predicate: P,
+        status: BlockStatus,
+        timeout: WaitTimeout,
+    ) -> Result<ObservedEvent<E>>
+    where
+        E: StaticEvent + Send + 'static,
+        P: Fn(&E) -> bool + Send + Sync;
+
+    /// Retrieves a storage value stored under given key, decoded according to the runtime
+    /// metadata, so that no statically generated storage address is needed.
+    ///
+    /// # Errors
+    /// Fails in case the storage entry is unknown, keys do not match it, or the value cannot be
+    /// decoded, but returns `Ok(None)` if there is no such value.
+    /// * `pallet` - name of the pallet, e.g. `System`
+    /// * `entry` - name of the storage entry, e.g. `Account`
+    /// * `keys` - keys of a storage map, empty for a plain storage value
+    /// * `at` - optional block hash to query state from
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let keys = vec![Value::from_bytes(&account_id)];
+    ///     let info = conn.get_dynamic_storage("System", "Account", keys, None).await?;
+    /// ```
+    async fn get_dynamic_storage(
+        &self,
+        pallet: &str,
+        entry: &str,
+        keys: Vec<Value>,
+        at: Option<BlockHash>,
+    ) -> Result<Option<DecodedValue>>;
+
+    /// Streams raw keys of all storage entries starting with `prefix`, e.g. of all entries of a
+    /// storage map. Keys are fetched from the node in pages, all at the same block: `at` or, if
+    /// not given, the best block at the time of fetching the first page.
+    /// * `prefix` - raw storage key prefix
+    /// * `page_size` - number of keys fetched in a single request, e.g.
+    /// [`DEFAULT_STORAGE_PAGE_SIZE`](crate::DEFAULT_STORAGE_PAGE_SIZE)
+    /// * `at` - optional block hash to query state from
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let prefix = [twox_128(b"Staking"), twox_128(b"Bonded")].concat();
+    ///     let keys: Vec<StorageKey> = conn
+    ///         .storage_keys_paged(prefix, DEFAULT_STORAGE_PAGE_SIZE, None)
+    ///         .try_collect()
+    ///         .await?;
+    /// ```
+    fn storage_keys_paged(
+        &self,
+        prefix: Vec<u8>,
+        page_size: u32,
+        at: Option<BlockHash>,
+    ) -> BoxStream<'_, Result<StorageKey>>;
+
+    /// Streams all entries of a storage map, decoded according to the runtime metadata. Entries
+    /// are fetched from the node in pages, all at the same block, see
+    /// [`Self::storage_keys_paged`].
+    ///
+    /// The map keys are decoded as `K` (a tuple for maps with many keys), as long as all hashers of
+    /// the map keep them in raw storage keys, i.e. they are `Blake2_128Concat`, `Twox64Concat` or
+    /// `Identity`.
+    /// * `addrs` - address of the map; it may contain some of the keys of a map with many keys, to
+    /// iterate only over the entries with these keys
+    /// * `page_size` - number of entries fetched in a single request
+    /// * `at` - optional block hash to query state from
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let addrs = api::storage().vesting().vesting_root();
+    ///     let mut schedules = conn.iter_storage_map::<AccountId, _>(&addrs, 100, None);
+    ///     while let Some(entry) = schedules.try_next().await? {
+    ///         println!("{:?}: {:?}", entry.map_key, entry.value);
+    ///     }
+    /// ```
+    fn iter_storage_map<'a, K, Address>(
+        &'a self,
+        addrs: &'a Address,
+        page_size: u32,
+        at: Option<BlockHash>,
+    ) -> BoxStream<'a, Result<StorageMapEntry<K, <Address::Target as DecodeWithMetadata>::Target>>>
+    where
+        K: Decode + Send + 'a,
+        Address: StorageAddress<IsIterable = Yes> + Sync,
+        <Address::Target as DecodeWithMetadata>::Target: Send + 'a;
+
+    /// Prepares a transaction for signing without access to the chain, e.g. on an air-gapped
+    /// machine, see [`UnsignedTx`]. The genesis hash and the runtime version are taken from the
+    /// connected node.
+    /// * `tx` - encoded transaction payload
+    /// * `nonce` - nonce of the account that will sign the transaction
+    /// * `params` - optional tx params e.g. tip or mortal era
+    async fn create_unsigned_tx<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        nonce: u32,
+        params: C::TxParams,
+    ) -> Result<UnsignedTx>;
+
+    /// Submits an extrinsic that was signed elsewhere, e.g. with
+    /// [`KeyPair::sign_tx`](crate::KeyPair::sign_tx).
+    /// * `extrinsic` - encoded extrinsic
+    ///
+    /// # Returns
+    /// A [`PendingTx`] handle that can be used to wait for the tx, as for
+    /// [`SignedConnectionApi::submit_tx`].
+    async fn submit_raw_extrinsic(&self, extrinsic: Vec<u8>) -> Result<PendingTx<C>>;
 
     /// Submit a RPC call.
     ///
@@ -131,39 +466,414 @@ pub trait ConnectionApi: Sync {
     /// let params = rpc_params!["ContractsApi_call", Bytes(args.encode())];
     /// rpc_call("state_call".to_string(), params).await;
     /// ```
-    async fn rpc_call<R: Decode>(&self, func_name: String, params: RpcParams) -> anyhow::Result<R>;
+    async fn rpc_call<R: Decode>(&self, func_name: String, params: RpcParams) -> Result<R>;
 
     /// Same as [rpc_call] but used for rpc endpoint that does not return values.
-    async fn rpc_call_no_return(&self, func_name: String, params: RpcParams) -> anyhow::Result<()>;
+    async fn rpc_call_no_return(&self, func_name: String, params: RpcParams) -> Result<()>;
 }
 
 /// Data regarding submitted transaction.
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
 pub struct TxInfo {
+    /// Hash of the block containing tx, `None` if the tx was only submitted.
+    pub block_hash: Option<BlockHash>,
+    /// Hash of the transaction itself.
+    pub tx_hash: TxHash,
+}
+
+impl<C: Config<Hash = BlockHash>> From<ExtrinsicEvents<C>> for TxInfo {
+    fn from(ee: ExtrinsicEvents<C>) -> Self {
+        Self {
+            block_hash: Some(ee.block_hash()),
+            tx_hash: ee.extrinsic_hash(),
+        }
+    }
+}
+
+/// Outcome of a transaction included in a block, together with the events it emitted.
+pub struct TxResult<C: Config = PolkadotConfig> {
     /// Hash of the block containing tx.
     pub block_hash: BlockHash,
+    /// Number of the block containing tx.
+    pub block_number: BlockNumber,
     /// Hash of the transaction itself.
     pub tx_hash: TxHash,
+    /// Index of the transaction within the block.
+    pub extrinsic_index: u32,
+    /// Events emitted by the transaction.
+    pub events: ExtrinsicEvents<C>,
+}
+
+impl<C: ChainConfig> TxResult<C> {
+    async fn new<X: AsConnection<C> + Sync>(
+        connection: &X,
+        events: ExtrinsicEvents<C>,
+    ) -> Result<Self> {
+        let block_hash = events.block_hash();
+        let header = connection
+            .as_connection()
+            .tx_client
+            .rpc()
+            .header(Some(block_hash))
+            .await?
+            .ok_or(Error::BlockNotFound(block_hash))?;
+
+        Ok(Self {
+            block_hash,
+            block_number: *header.number(),
+            tx_hash: events.extrinsic_hash(),
+            extrinsic_index: events.extrinsic_index(),
+            events,
+        })
+    }
+
+    /// Returns all events of type `E` emitted by the transaction.
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let result = conn.send_tx_with_events(tx, TxStatus::InBlock).await?;
+    ///     let deployed = result.find::<api::contracts::events::Instantiated>()?;
+    /// ```
+    pub fn find<E: StaticEvent>(&self) -> Result<Vec<E>> {
+        self.events
+            .find::<E>()
+            .map(|event| event.map_err(Error::from))
+            .collect()
+    }
+
+    /// Returns the first event of type `E` emitted by the transaction, if any.
+    pub fn find_first<E: StaticEvent>(&self) -> Result<Option<E>> {
+        Ok(self.events.find_first::<E>()?)
+    }
+
+    /// Checks whether the transaction emitted any event of type `E`.
+    pub fn has<E: StaticEvent>(&self) -> Result<bool> {
+        Ok(self.events.has::<E>()?)
+    }
+}
+
+impl<C: Config> fmt::Debug for TxResult<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Events are listed by name, so that no `Debug` bound is needed on the chain config.
+        let events = self
+            .events
+            .iter()
+            .map(|event| match event {
+                Ok(event) => format!("{}::{}", event.pallet_name(), event.variant_name()),
+                Err(e) => format!("<{e}>"),
+            })
+            .collect::<Vec<_>>();
+
+        f.debug_struct("TxResult")
+            .field("block_hash", &self.block_hash)
+            .field("block_number", &self.block_number)
+            .field("tx_hash", &self.tx_hash)
+            .field("extrinsic_index", &self.extrinsic_index)
+            .field("events", &events)
+            .finish()
+    }
 }
 
-impl From<ExtrinsicEvents<PolkadotConfig>> for TxInfo {
-    fn from(ee: ExtrinsicEvents<PolkadotConfig>) -> Self {
+impl<C: Config> From<&TxResult<C>> for TxInfo {
+    fn from(result: &TxResult<C>) -> Self {
         Self {
-            block_hash: ee.block_hash(),
-            tx_hash: ee.extrinsic_hash(),
+            block_hash: Some(result.block_hash),
+            tx_hash: result.tx_hash,
+        }
+    }
+}
+
+/// Upper bounds on waiting for a transaction to reach consecutive [`TxStatus`] stages. `None`
+/// means waiting indefinitely.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TxTimeouts {
+    /// How long to wait for a tx to be included in a block, counting from when waiting started.
+    pub in_block: Option<Duration>,
+    /// How long to wait for the block including a tx to be finalized, counting from inclusion.
+    pub finalized: Option<Duration>,
+}
+
+/// The most recent status of a transaction reported by the node.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ObservedTxStatus {
+    /// No status has been reported yet.
+    Submitted,
+    /// The tx is in the pool, but cannot be included yet, e.g. because of a nonce gap.
+    Future,
+    /// The tx is in the pool, ready to be included.
+    Ready,
+    /// The tx has been broadcast to other nodes.
+    Broadcast,
+    /// The tx is included in a block.
+    InBlock(BlockHash),
+    /// The block including the tx has been retracted.
+    Retracted(BlockHash),
+    /// The block including the tx has been finalized.
+    Finalized(BlockHash),
+}
+
+/// Handle to a submitted transaction, which allows to wait for its inclusion later on.
+///
+/// Dropping the handle stops watching the transaction, but does not affect the transaction itself.
+///
+/// # Examples
+/// ```ignore
+///     let mut pending = conn.submit_tx(tx, Default::default()).await?;
+///     // do something else in the meantime
+///     if pending.status()? != TxStatus::Finalized {
+///         let timeouts = TxTimeouts {
+///             in_block: Some(Duration::from_secs(30)),
+///             finalized: Some(Duration::from_secs(60)),
+///         };
+///         let info = pending.with_timeouts(timeouts).wait_for(TxStatus::Finalized).await?;
+///     }
+/// ```
+pub struct PendingTx<C: Config = PolkadotConfig> {
+    tx_hash: TxHash,
+    progress: TxProgress<C, OnlineClient<C>>,
+    timeouts: TxTimeouts,
+    status: TxStatus,
+    observed: ObservedTxStatus,
+    in_block: Option<TxInBlock<C, OnlineClient<C>>>,
+    expiry: Option<TxExpiry<C>>,
+}
+
+/// End of the era of a mortal transaction.
+struct TxExpiry<C: Config> {
+    /// Client used to check how far the chain has progressed.
+    client: OnlineClient<C>,
+    /// Number of the first block in which the transaction is no longer valid.
+    expired_at: BlockNumber,
+}
+
+impl<C: ChainConfig> PendingTx<C> {
+    fn new(progress: TxProgress<C, OnlineClient<C>>, timeouts: TxTimeouts) -> Self {
+        Self {
+            tx_hash: progress.extrinsic_hash(),
+            progress,
+            timeouts,
+            status: TxStatus::Submitted,
+            observed: ObservedTxStatus::Submitted,
+            in_block: None,
+            expiry: None,
+        }
+    }
+
+    /// Marks the transaction as mortal, no longer valid from the block with `expired_at` on.
+    fn mortal(self, client: OnlineClient<C>, expired_at: BlockNumber) -> Self {
+        Self {
+            expiry: Some(TxExpiry { client, expired_at }),
+            ..self
+        }
+    }
+
+    /// Overrides timeouts inherited from the connection, for this transaction only.
+    pub fn with_timeouts(self, timeouts: TxTimeouts) -> Self {
+        Self { timeouts, ..self }
+    }
+
+    /// Returns hash of the transaction.
+    pub fn tx_hash(&self) -> TxHash {
+        self.tx_hash
+    }
+
+    /// Returns hash of the block containing the transaction, as of the last observed status.
+    pub fn block_hash(&self) -> Option<BlockHash> {
+        self.in_block.as_ref().map(|in_block| in_block.block_hash())
+    }
+
+    /// Returns the last status reported by the node, without waiting for any new updates.
+    pub fn last_observed(&self) -> ObservedTxStatus {
+        self.observed
+    }
+
+    /// Returns the current status of the transaction, without waiting for any new updates.
+    ///
+    /// Fails with [`Error::TxNotIncluded`] if the transaction will never be included in a block.
+    pub fn status(&mut self) -> Result<TxStatus> {
+        while let Some(update) = self.progress.next().now_or_never() {
+            match update {
+                Some(update) => self.update(update?)?,
+                None => break,
+            }
+        }
+
+        Ok(self.status)
+    }
+
+    /// Waits until the transaction reaches `status`. For [`TxStatus::InBlock`] and
+    /// [`TxStatus::Finalized`], it also checks that the transaction succeeded.
+    ///
+    /// Fails with [`Error::TxTimeout`] if any stage takes longer than allowed by the timeouts, and
+    /// with [`Error::TxExpired`] if a mortal transaction is dropped after its era ended.
+    ///
+    /// # Returns
+    /// Block hash of block where transaction was put together with transaction hash, or error.
+    pub async fn wait_for(self, status: TxStatus) -> Result<TxInfo> {
+        if status == TxStatus::Submitted {
+            return Ok(TxInfo {
+                block_hash: self.block_hash(),
+                tx_hash: self.tx_hash,
+            });
+        }
+
+        let info: TxInfo = self.wait_for_success(status).await?.into();
+        info!(target: "subxtxt", "tx with hash {:?} included in block {:?}", info.tx_hash, info.block_hash);
+
+        Ok(info)
+    }
+
+    /// Waits until the transaction reaches `status` and returns the events it emitted.
+    /// [`TxStatus::Submitted`] is treated as [`TxStatus::InBlock`].
+    async fn wait_for_success(mut self, status: TxStatus) -> Result<ExtrinsicEvents<C>> {
+        let status = match status {
+            TxStatus::Submitted => TxStatus::InBlock,
+            status => status,
+        };
+
+        let mut stage = self.stage();
+        let mut stage_start = Instant::now();
+        while !self.reached(status) {
+            let next = self.progress.next();
+            let update = match self.timeouts.for_stage(stage) {
+                Some(timeout) => match tokio::time::timeout_at(stage_start + timeout, next).await {
+                    Ok(update) => update,
+                    Err(_) => {
+                        return Err(Error::TxTimeout {
+                            tx_hash: self.tx_hash,
+                            stage,
+                            last_status: self.observed,
+                        })
+                    }
+                },
+                None => next.await,
+            };
+            let result = match update {
+                Some(update) => update
+                    .map_err(Error::from)
+                    .and_then(|update| self.update(update)),
+                None => Err(Error::TxNotIncluded {
+                    tx_hash: self.tx_hash,
+                    reason: "transaction watching ended unexpectedly".to_string(),
+                }),
+            };
+            if let Err(e) = result {
+                return Err(self.check_expiry(e, stage).await);
+            }
+
+            if self.stage() != stage {
+                stage = self.stage();
+                stage_start = Instant::now();
+            }
+        }
+
+        let in_block = self.in_block.ok_or_else(|| Error::TxNotIncluded {
+            tx_hash: self.tx_hash,
+            reason: "no block including the transaction was reported".to_string(),
+        })?;
+        Ok(in_block.wait_for_success().await?)
+    }
+
+    /// Replaces [`Error::TxNotIncluded`] with [`Error::TxExpired`] if the transaction is mortal and
+    /// the best block is already beyond its era.
+    async fn check_expiry(&self, error: Error, stage: TxStatus) -> Error {
+        let expiry = match (&error, &self.expiry) {
+            (Error::TxNotIncluded { .. }, Some(expiry)) => expiry,
+            _ => return error,
+        };
+
+        match expiry.client.rpc().header(None).await {
+            Ok(Some(best)) if *best.number() >= expiry.expired_at => Error::TxExpired {
+                tx_hash: self.tx_hash,
+                stage,
+                expired_at: expiry.expired_at,
+            },
+            _ => error,
+        }
+    }
+
+    /// Returns the status the transaction is currently waiting for.
+    fn stage(&self) -> TxStatus {
+        match self.status {
+            TxStatus::Submitted => TxStatus::InBlock,
+            _ => TxStatus::Finalized,
+        }
+    }
+
+    fn reached(&self, status: TxStatus) -> bool {
+        match status {
+            TxStatus::Submitted => true,
+            TxStatus::InBlock => self.status != TxStatus::Submitted,
+            TxStatus::Finalized => self.status == TxStatus::Finalized,
+        }
+    }
+
+    fn update(&mut self, update: SubxtTxStatus<C, OnlineClient<C>>) -> Result<()> {
+        let reason = match update {
+            SubxtTxStatus::Future => {
+                self.observed = ObservedTxStatus::Future;
+                return Ok(());
+            }
+            SubxtTxStatus::Ready => {
+                self.observed = ObservedTxStatus::Ready;
+                return Ok(());
+            }
+            SubxtTxStatus::Broadcast(_) => {
+                self.observed = ObservedTxStatus::Broadcast;
+                return Ok(());
+            }
+            SubxtTxStatus::InBlock(in_block) => {
+                self.status = TxStatus::InBlock;
+                self.observed = ObservedTxStatus::InBlock(in_block.block_hash());
+                self.in_block = Some(in_block);
+                return Ok(());
+            }
+            SubxtTxStatus::Finalized(in_block) => {
+                self.status = TxStatus::Finalized;
+                self.observed = ObservedTxStatus::Finalized(in_block.block_hash());
+                self.in_block = Some(in_block);
+                return Ok(());
+            }
+            SubxtTxStatus::Retracted(block_hash) => {
+                self.status = TxStatus::Submitted;
+                self.observed = ObservedTxStatus::Retracted(block_hash);
+                self.in_block = None;
+                return Ok(());
+            }
+            SubxtTxStatus::FinalityTimeout(_) => "finality timeout",
+            SubxtTxStatus::Usurped(_) => "usurped by another transaction",
+            SubxtTxStatus::Dropped => "dropped from the transaction pool",
+            SubxtTxStatus::Invalid => "invalid",
+        };
+
+        Err(Error::TxNotIncluded {
+            tx_hash: self.tx_hash,
+            reason: reason.to_string(),
+        })
+    }
+}
+
+impl TxTimeouts {
+    fn for_stage(&self, stage: TxStatus) -> Option<Duration> {
+        match stage {
+            TxStatus::InBlock => self.in_block,
+            TxStatus::Finalized => self.finalized,
+            TxStatus::Submitted => None,
         }
     }
 }
 
 /// Signed connection should be able to sends transactions to chain
 #[async_trait::async_trait]
-pub trait SignedConnectionApi: ConnectionApi {
+pub trait SignedConnectionApi<C: ChainConfig = PolkadotConfig>: ConnectionApi<C> {
     /// Send a transaction to a chain. It waits for a given tx `status`.
     /// * `tx` - encoded transaction payload
     /// * `status` - a [`TxStatus`] for a tx to wait for
     ///
     /// # Returns
     /// Block hash of block where transaction was put together with transaction hash, or error.
+    /// If the transaction was included, but failed, the error is [`Error::Dispatch`] describing
+    /// the reason, e.g. `Balances::InsufficientBalance`.
     ///
     /// # Examples
     /// ```ignore
@@ -176,7 +886,7 @@ pub trait SignedConnectionApi: ConnectionApi {
         &self,
         tx: Call,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// Send a transaction to a chain. It waits for a given tx `status`.
     /// * `tx` - encoded transaction payload
@@ -185,33 +895,195 @@ pub trait SignedConnectionApi: ConnectionApi {
     ///
     /// # Returns
     /// Block hash of block where transaction was put together with transaction hash, or error.
+    /// For [`TxStatus::Submitted`] the block hash is `None`.
     async fn send_tx_with_params<Call: TxPayload + Send + Sync>(
         &self,
         tx: Call,
-        params: PolkadotExtrinsicParamsBuilder<SubstrateConfig>,
+        params: C::TxParams,
+        status: TxStatus,
+    ) -> Result<TxInfo>;
+
+    /// Send a mortal transaction to a chain. It waits for a given tx `status`.
+    /// * `tx` - encoded transaction payload
+    /// * `lifetime` - number of blocks, counting from the current finalized head, during which the
+    ///   tx can be included, see [`SignedConnectionApi::submit_mortal_tx`]
+    /// * `status` - a [`TxStatus`] of a tx to wait for
+    ///
+    /// # Returns
+    /// Block hash of block where transaction was put together with transaction hash, or error.
+    /// If the transaction was dropped after its era ended, the error is [`Error::TxExpired`].
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     match conn.send_mortal_tx(tx, 64, TxStatus::Finalized).await {
+    ///         Err(Error::TxExpired { .. }) => { /* safe to send again */ }
+    ///         result => { /* ... */ }
+    ///     }
+    /// ```
+    async fn send_mortal_tx<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        lifetime: BlockNumber,
+        status: TxStatus,
+    ) -> Result<TxInfo>
+    where
+        C::TxParams: MortalTxParams;
+
+    /// Submit a mortal transaction to a chain without waiting for it to be included in a block.
+    /// Its era starts at the current finalized head, which is fetched from the node. Once the era
+    /// ends, the transaction can no longer be included, even if it is resubmitted.
+    /// * `tx` - encoded transaction payload
+    /// * `lifetime` - number of blocks during which the tx can be included. It is rounded up to a
+    ///   power of two between 4 and 65536, and the era is aligned to a multiple of a quarter of
+    ///   it, so the tx may expire a bit earlier.
+    /// * `params` - optional tx params e.g. tip; any era set in them is overridden
+    ///
+    /// # Returns
+    /// A [`PendingTx`] handle that can be used to wait for the tx later on, or error.
+    async fn submit_mortal_tx<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        lifetime: BlockNumber,
+        params: C::TxParams,
+    ) -> Result<PendingTx<C>>
+    where
+        C::TxParams: MortalTxParams;
+
+    /// Send a transaction to a chain, with the nonce taken from the [`NonceManager`] of this
+    /// connection instead of the node. It waits for a given tx `status`.
+    ///
+    /// Use it to send many transactions quickly, even concurrently, from a single account. If the
+    /// transaction fails for any reason other than a failed dispatch, e.g. it cannot be signed,
+    /// submitted or included, the nonce manager is resynced with the node.
+    /// Mixing it with other ways of sending transactions from the same account may lead to stale
+    /// nonces, which are then fixed by resyncing.
+    /// * `tx` - encoded transaction payload
+    /// * `status` - a [`TxStatus`] of a tx to wait for
+    ///
+    /// # Returns
+    /// Block hash of block where transaction was put together with transaction hash, or error.
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let txs = (0..100).map(|_| conn.send_tx_with_nonce(tx.clone(), TxStatus::Submitted));
+    ///     futures::future::try_join_all(txs).await?;
+    /// ```
+    async fn send_tx_with_nonce<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        status: TxStatus,
+    ) -> Result<TxInfo>;
+
+    /// Submit a transaction to a chain without waiting for it to be included in a block.
+    /// * `tx` - encoded transaction payload
+    /// * `params` - optional tx params e.g. tip
+    ///
+    /// # Returns
+    /// A [`PendingTx`] handle that can be used to wait for the tx later on, or error.
+    async fn submit_tx<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        params: C::TxParams,
+    ) -> Result<PendingTx<C>>;
+
+    /// Send a transaction to a chain and return the events it emitted. It waits for a given tx
+    /// `status`; since events are known only once the tx is included in a block,
+    /// [`TxStatus::Submitted`] is treated as [`TxStatus::InBlock`].
+    /// * `tx` - encoded transaction payload
+    /// * `status` - a [`TxStatus`] of a tx to wait for
+    ///
+    /// # Returns
+    /// Block hash and number, transaction hash and index, and decoded events, or error.
+    async fn send_tx_with_events<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        status: TxStatus,
+    ) -> Result<TxResult<C>>;
+
+    /// Send a transaction built from the runtime metadata, so that no statically generated call
+    /// is needed. It waits for a given tx `status`.
+    /// * `pallet` - name of the pallet, e.g. `Balances`
+    /// * `call` - name of the call, e.g. `transfer`
+    /// * `fields` - arguments of the call, in order
+    /// * `status` - a [`TxStatus`] of a tx to wait for
+    ///
+    /// # Returns
+    /// Block hash of block where transaction was put together with transaction hash, or error.
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let dest = Value::unnamed_variant("Id", [Value::from_bytes(&dest)]);
+    ///     let fields = vec![dest, Value::u128(amount)];
+    ///     conn.send_dynamic_tx("Balances", "transfer", fields, TxStatus::InBlock).await
+    /// ```
+    async fn send_dynamic_tx(
+        &self,
+        pallet: &str,
+        call: &str,
+        fields: Vec<Value>,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
+
+    /// Estimates the cost of a transaction without sending it. The transaction is signed as if it
+    /// was sent with [`Self::send_tx`].
+    /// * `tx` - encoded transaction payload
+    ///
+    /// # Returns
+    /// Weight and dispatch class of the transaction, together with the fee and its breakdown.
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     let estimate = conn.estimate_fee(tx.clone()).await?;
+    ///     if estimate.partial_fee <= budget {
+    ///         conn.send_tx(tx, TxStatus::InBlock).await?;
+    ///     }
+    /// ```
+    async fn estimate_fee<Call: TxPayload + Send + Sync>(&self, tx: Call) -> Result<FeeEstimate>;
+
+    /// Runs a transaction against the state of the best block without sending it. The
+    /// transaction is signed as if it was sent with [`Self::send_tx`]. Requires the node to
+    /// expose unsafe RPC methods.
+    /// * `tx` - encoded transaction payload
+    ///
+    /// # Returns
+    /// Whether the transaction would be included, and whether its dispatch would succeed.
+    ///
+    /// # Examples
+    /// ```ignore
+    ///     match conn.dry_run(tx).await? {
+    ///         DryRunOutcome::DispatchFailed(e) if e.is("Balances", "InsufficientBalance") => {}
+    ///         outcome => { /* ... */ }
+    ///     }
+    /// ```
+    async fn dry_run<Call: TxPayload + Send + Sync>(&self, tx: Call) -> Result<DryRunOutcome>;
 
     /// Returns account id which signs this connection
-    fn account_id(&self) -> &AccountId;
+    fn account_id(&self) -> &C::AccountId;
 
-    /// Returns a [`KeyPair`] which signs this connection
-    fn signer(&self) -> &KeyPair;
+    /// Returns the [`Signer`] which signs this connection
+    fn signer(&self) -> &dyn Signer<C>;
 
     /// Tries to convert [`SignedConnection`] as [`RootConnection`]
-    async fn try_as_root(&self) -> anyhow::Result<RootConnection>;
+    ///
+    /// Fails with [`Error::NotSudo`] if the signer is not the current `Sudo::Key`.
+    async fn try_as_root(&self) -> Result<RootConnection<C>>
+    where
+        C: ChainConfig<AccountId = AccountId>;
 }
 
 #[async_trait::async_trait]
-impl<C: AsConnection + Sync> ConnectionApi for C {
+impl<C: ChainConfig, X: AsConnection<C> + Sync> ConnectionApi<C> for X {
     async fn get_storage_entry<T: DecodeWithMetadata + Sync, Defaultable: Sync, Iterable: Sync>(
         &self,
         addrs: &StaticStorageAddress<T, Yes, Defaultable, Iterable>,
         at: Option<BlockHash>,
-    ) -> T::Target {
+    ) -> Result<T::Target> {
         self.get_storage_entry_maybe(addrs, at)
-            .await
-            .expect("There should be a value")
+            .await?
+            .ok_or_else(|| Error::MissingStorage {
+                pallet: addrs.pallet_name().to_string(),
+                entry: addrs.entry_name().to_string(),
+            })
     }
 
     async fn get_storage_entry_maybe<
@@ -222,48 +1094,297 @@ impl<C: AsConnection + Sync> ConnectionApi for C {
         &self,
         addrs: &StaticStorageAddress<T, Yes, Defaultable, Iterable>,
         at: Option<BlockHash>,
-    ) -> Option<T::Target> {
+    ) -> Result<Option<T::Target>> {
         info!(target: "subxtxt", "accessing storage at {}::{} at block {:?}", addrs.pallet_name(), addrs.entry_name(), at);
-        self.as_connection()
-            .as_client()
-            .storage()
-            .fetch(addrs, at)
+        let connection = self.as_connection();
+        connection
+            .retry_request(|| async move {
+                connection
+                    .as_client()
+                    .storage()
+                    .fetch(addrs, at)
+                    .await
+                    .map_err(Error::from)
+            })
             .await
-            .expect("Should access storage")
     }
 
-    async fn rpc_call<R: Decode>(&self, func_name: String, params: RpcParams) -> anyhow::Result<R> {
-        info!(target: "subxtxt", "submitting rpc call `{}`, with params {:?}", func_name, params.clone().build());
-        let bytes: Bytes = self
-            .as_connection()
-            .as_client()
-            .rpc()
-            .request(&func_name, params)
+    async fn get_storage_entries<Address: StorageAddress<IsFetchable = Yes> + Sync>(
+        &self,
+        addrs: &[Address],
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Option<<Address::Target as DecodeWithMetadata>::Target>>> {
+        info!(target: "subxtxt", "accessing {} storage entries at block {:?}", addrs.len(), at);
+        let connection = self.as_connection();
+        let metadata = connection.as_client().metadata();
+        let keys = addrs
+            .iter()
+            .map(|address| storage_key(&metadata, address).map(StorageKey))
+            .collect::<Result<Vec<_>>>()?;
+
+        let values = connection
+            .storage_values(&keys, at)
+            .await?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        addrs
+            .iter()
+            .zip(keys)
+            .map(|(address, key)| match values.get(&key) {
+                Some(value) => decode_value(&metadata, address, value).map(Some),
+                None => Ok(None),
+            })
+            .collect()
+    }
+
+    async fn subscribe_storage<Address>(
+        &self,
+        addrs: &[Address],
+    ) -> Result<Vec<StorageChanges<<Address::Target as DecodeWithMetadata>::Target>>>
+    where
+        Address: StorageAddress<IsFetchable = Yes> + Sync,
+        Address::Target: 'static,
+        <Address::Target as DecodeWithMetadata>::Target: Send,
+    {
+        info!(target: "subxtxt", "subscribing to {} storage entries", addrs.len());
+        let connection = self.as_connection();
+        let metadata = connection.as_client().metadata();
+
+        // An empty list of keys would subscribe to changes of the whole storage.
+        if addrs.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let keys = addrs
+            .iter()
+            .map(|address| storage_key(&metadata, address).map(StorageKey))
+            .collect::<Result<Vec<_>>>()?;
+        let value_types = addrs
+            .iter()
+            .map(|address| value_type(&metadata, address))
+            .collect::<Result<Vec<_>>>()?;
+        let subscription = connection
+            .retry_request(|| {
+                let keys = keys.clone();
+                async move {
+                    connection
+                        .as_client()
+                        .rpc()
+                        .subscribe(
+                            "state_subscribeStorage",
+                            rpc_params![keys],
+                            "state_unsubscribeStorage",
+                        )
+                        .await
+                        .map_err(Error::from)
+                }
+            })
             .await?;
 
-        Ok(R::decode(&mut bytes.as_ref())?)
+        Ok(split_storage_changes::<Address::Target>(
+            subscription,
+            keys,
+            value_types,
+            metadata,
+        ))
+    }
+
+    async fn subscribe_best_blocks(&self) -> Result<BlockStream<C>> {
+        info!(target: "subxtxt", "subscribing to best blocks");
+        let connection = self.as_connection();
+        let headers = connection
+            .retry_request(|| async move {
+                connection
+                    .as_client()
+                    .rpc()
+                    .subscribe_best_block_headers()
+                    .await
+                    .map_err(Error::from)
+            })
+            .await?;
+
+        Ok(best_blocks(connection.clone(), headers))
     }
 
-    async fn rpc_call_no_return(&self, func_name: String, params: RpcParams) -> anyhow::Result<()> {
+    async fn subscribe_finalized_blocks(&self) -> Result<BlockStream<C>> {
+        info!(target: "subxtxt", "subscribing to finalized blocks");
+        let connection = self.as_connection();
+        let headers = connection
+            .retry_request(|| async move {
+                connection
+                    .as_client()
+                    .rpc()
+                    .subscribe_finalized_block_headers()
+                    .await
+                    .map_err(Error::from)
+            })
+            .await?;
+
+        Ok(finalized_blocks(connection.clone(), headers))
+    }
+
+    async fn subscribe_events<E: StaticEvent + Send + 'static>(
+        &self,
+        status: BlockStatus,
+    ) -> Result<EventStream<E>> {
+        info!(target: "subxtxt", "subscribing to {}::{} events in {:?} blocks", E::PALLET, E::EVENT, status);
+        let blocks = subscribe_blocks(self.as_connection(), status).await?;
+        Ok(events(blocks))
+    }
+
+    async fn wait_for_event<E, P>(
+        &self,
+        predicate: P,
+        status: BlockStatus,
+        timeout: WaitTimeout,
+    ) -> Result<ObservedEvent<E>>
+    where
+        E: StaticEvent + Send + 'static,
+        P: Fn(&E) -> bool + Send + Sync,
+    {
+        info!(target: "subxtxt", "waiting for {}::{} event in {:?} blocks, at most {:?}", E::PALLET, E::EVENT, status, timeout);
+        let blocks = subscribe_blocks(self.as_connection(), status).await?;
+        find_event(blocks, predicate, timeout).await
+    }
+
+    async fn get_dynamic_storage(
+        &self,
+        pallet: &str,
+        entry: &str,
+        keys: Vec<Value>,
+        at: Option<BlockHash>,
+    ) -> Result<Option<DecodedValue>> {
+        self.as_connection()
+            .dynamic_storage_thunk(pallet, entry, keys, at)
+            .await?
+            .map(|thunk| thunk.to_value().map_err(Error::from))
+            .transpose()
+    }
+
+    fn storage_keys_paged(
+        &self,
+        prefix: Vec<u8>,
+        page_size: u32,
+        at: Option<BlockHash>,
+    ) -> BoxStream<'_, Result<StorageKey>> {
+        key_pages(self.as_connection(), prefix, page_size, at)
+            .map_ok(|keys| stream::iter(keys.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed()
+    }
+
+    fn iter_storage_map<'a, K, Address>(
+        &'a self,
+        addrs: &'a Address,
+        page_size: u32,
+        at: Option<BlockHash>,
+    ) -> BoxStream<'a, Result<StorageMapEntry<K, <Address::Target as DecodeWithMetadata>::Target>>>
+    where
+        K: Decode + Send + 'a,
+        Address: StorageAddress<IsIterable = Yes> + Sync,
+        <Address::Target as DecodeWithMetadata>::Target: Send + 'a,
+    {
+        info!(target: "subxtxt", "iterating over storage map {}::{} at block {:?}", addrs.pallet_name(), addrs.entry_name(), at);
+        let connection = self.as_connection();
+        let entries = async move {
+            let decoder = StorageMapDecoder::new(connection.as_client().metadata(), addrs)?;
+            let at = match at {
+                Some(at) => at,
+                None => connection.best_block_hash().await?,
+            };
+
+            let decoder = Arc::new(decoder);
+            let pages = key_pages(connection, decoder.prefix().to_vec(), page_size, Some(at));
+            Ok::<_, Error>(
+                pages
+                    .and_then(move |keys| {
+                        let decoder = decoder.clone();
+                        async move {
+                            let values = connection.storage_values(&keys, Some(at)).await?;
+                            let entries = values
+                                .into_iter()
+                                .map(|(key, value)| {
+                                    decoder.decode::<K, Address::Target>(key, &value)
+                                })
+                                .collect::<Vec<_>>();
+                            Ok(stream::iter(entries))
+                        }
+                    })
+                    .try_flatten(),
+            )
+        };
+
+        stream::once(entries).try_flatten().boxed()
+    }
+
+    async fn create_unsigned_tx<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        nonce: u32,
+        params: C::TxParams,
+    ) -> Result<UnsignedTx> {
+        unsigned_tx(self.as_connection().as_client(), &tx, nonce, params)
+    }
+
+    async fn submit_raw_extrinsic(&self, extrinsic: Vec<u8>) -> Result<PendingTx<C>> {
+        info!(target: "subxtxt", "submitting raw extrinsic of {} bytes", extrinsic.len());
+        let connection = self.as_connection();
+        let progress = SubmittableExtrinsic::from_bytes(connection.tx_client.clone(), extrinsic)
+            .submit_and_watch()
+            .await?;
+
+        Ok(PendingTx::new(progress, connection.tx_timeouts))
+    }
+
+    async fn rpc_call<R: Decode>(&self, func_name: String, params: RpcParams) -> Result<R> {
         info!(target: "subxtxt", "submitting rpc call `{}`, with params {:?}", func_name, params.clone().build());
-        let _: () = self
-            .as_connection()
-            .as_client()
-            .rpc()
-            .request(&func_name, params)
+        let connection = self.as_connection();
+        let func_name = func_name.as_str();
+        let bytes: Bytes = connection
+            .retry_request(|| {
+                let params = params.clone();
+                async move {
+                    connection
+                        .as_client()
+                        .rpc()
+                        .request(func_name, params)
+                        .await
+                        .map_err(Error::from)
+                }
+            })
             .await?;
 
-        Ok(())
+        Ok(R::decode(&mut bytes.as_ref())?)
+    }
+
+    async fn rpc_call_no_return(&self, func_name: String, params: RpcParams) -> Result<()> {
+        info!(target: "subxtxt", "submitting rpc call `{}`, with params {:?}", func_name, params.clone().build());
+        let connection = self.as_connection();
+        let func_name = func_name.as_str();
+        connection
+            .retry_request(|| {
+                let params = params.clone();
+                async move {
+                    connection
+                        .as_client()
+                        .rpc()
+                        .request(func_name, params)
+                        .await
+                        .map_err(Error::from)
+                }
+            })
+            .await
     }
 }
 
 #[async_trait::async_trait]
-impl<S: AsSigned + Sync> SignedConnectionApi for S {
+impl<C: ChainConfig, S: AsSigned<C> + Sync> SignedConnectionApi<C> for S {
     async fn send_tx<Call: TxPayload + Send + Sync>(
         &self,
         tx: Call,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo> {
+    ) -> Result<TxInfo> {
         self.send_tx_with_params(tx, Default::default(), status)
             .await
     }
@@ -271,101 +1392,613 @@ impl<S: AsSigned + Sync> SignedConnectionApi for S {
     async fn send_tx_with_params<Call: TxPayload + Send + Sync>(
         &self,
         tx: Call,
-        params: PolkadotExtrinsicParamsBuilder<SubstrateConfig>,
+        params: C::TxParams,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo> {
-        if let Some(details) = tx.validation_details() {
-            info!(target:"subxtxt", "Sending extrinsic {}.{} with params: {:?}", details.pallet_name, details.call_name, params);
-        }
+    ) -> Result<TxInfo> {
+        self.submit_tx(tx, params).await?.wait_for(status).await
+    }
 
-        let progress = self
-            .as_connection()
-            .as_client()
-            .tx()
-            .sign_and_submit_then_watch(&tx, self.as_signed().signer().pair_signer(), params)
+    async fn submit_tx<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        params: C::TxParams,
+    ) -> Result<PendingTx<C>> {
+        let progress = submit_and_watch(self, &tx, params, None).await?;
+        Ok(PendingTx::new(progress, self.as_connection().tx_timeouts))
+    }
+
+    async fn send_mortal_tx<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        lifetime: BlockNumber,
+        status: TxStatus,
+    ) -> Result<TxInfo>
+    where
+        C::TxParams: MortalTxParams,
+    {
+        self.submit_mortal_tx(tx, lifetime, Default::default())
+            .await?
+            .wait_for(status)
             .await
-            .map_err(|e| anyhow!("Failed to submit transaction: {:?}", e))?;
+    }
 
-        let info: TxInfo = match status {
-            TxStatus::InBlock => progress
-                .wait_for_in_block()
-                .await?
-                .wait_for_success()
-                .await?
-                .into(),
-            TxStatus::Finalized => progress.wait_for_finalized_success().await?.into(),
-            // In case of Submitted block hash does not mean anything
-            TxStatus::Submitted => {
-                return Ok(TxInfo {
-                    block_hash: Default::default(),
-                    tx_hash: progress.extrinsic_hash(),
-                })
+    async fn submit_mortal_tx<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        lifetime: BlockNumber,
+        params: C::TxParams,
+    ) -> Result<PendingTx<C>>
+    where
+        C::TxParams: MortalTxParams,
+    {
+        let connection = self.as_connection();
+        // The checkpoint must be known to the node that validates the transaction.
+        let client = &connection.tx_client;
+        let (checkpoint, number) = connection
+            .retry_request(|| async move {
+                let hash = client.rpc().finalized_head().await?;
+                let header = client
+                    .rpc()
+                    .header(Some(hash))
+                    .await?
+                    .ok_or(Error::BlockNotFound(hash))?;
+                Ok::<_, Error>((hash, *header.number()))
+            })
+            .await?;
+
+        let era = Era::mortal(lifetime as u64, number as u64);
+        let params = params.mortal(era, checkpoint);
+        let progress = submit_and_watch(self, &tx, params, None).await?;
+
+        Ok(PendingTx::new(progress, connection.tx_timeouts)
+            .mortal(client.clone(), era.death(number as u64) as BlockNumber))
+    }
+
+    async fn send_tx_with_nonce<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let nonce_manager = &self.as_signed().nonce_manager;
+        let nonce = nonce_manager.next_nonce(self, self.account_id()).await?;
+
+        let progress = match submit_and_watch(self, &tx, Default::default(), Some(nonce)).await {
+            Ok(progress) => progress,
+            Err(e) => {
+                // Whatever failed, the nonce has not been used by any transaction in the pool.
+                nonce_manager.resync().await;
+                return Err(e);
             }
         };
-        info!(target: "subxtxt", "tx with hash {:?} included in block {:?}", info.tx_hash, info.block_hash);
 
-        Ok(info)
+        let result = PendingTx::new(progress, self.as_connection().tx_timeouts)
+            .wait_for(status)
+            .await;
+        // A failed dispatch means that the transaction was included, so its nonce is used.
+        if matches!(result, Err(ref e) if !matches!(e, Error::Dispatch(_))) {
+            nonce_manager.resync().await;
+        }
+
+        result
+    }
+
+    async fn send_tx_with_events<Call: TxPayload + Send + Sync>(
+        &self,
+        tx: Call,
+        status: TxStatus,
+    ) -> Result<TxResult<C>> {
+        let events = self
+            .submit_tx(tx, Default::default())
+            .await?
+            .wait_for_success(status)
+            .await?;
+        let result = TxResult::new(self, events).await?;
+        info!(target: "subxtxt", "tx with hash {:?} included in block {:?}", result.tx_hash, result.block_hash);
+
+        Ok(result)
+    }
+
+    async fn send_dynamic_tx(
+        &self,
+        pallet: &str,
+        call: &str,
+        fields: Vec<Value>,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        self.send_tx(dynamic::tx(pallet, call, fields), status)
+            .await
+    }
+
+    async fn estimate_fee<Call: TxPayload + Send + Sync>(&self, tx: Call) -> Result<FeeEstimate> {
+        let extrinsic = sign(self, &tx, Default::default(), None).await?;
+        estimate_fee(self, &extrinsic).await
+    }
+
+    async fn dry_run<Call: TxPayload + Send + Sync>(&self, tx: Call) -> Result<DryRunOutcome> {
+        let extrinsic = sign(self, &tx, Default::default(), None).await?;
+        let metadata = self.as_connection().as_client().metadata();
+        dry_run(self, &extrinsic, &metadata).await
     }
 
-    fn account_id(&self) -> &AccountId {
+    fn account_id(&self) -> &C::AccountId {
         self.as_signed().signer().account_id()
     }
 
-    fn signer(&self) -> &KeyPair {
-        &self.as_signed().signer
+    fn signer(&self) -> &dyn Signer<C> {
+        self.as_signed().signer.as_ref()
     }
 
-    async fn try_as_root(&self) -> anyhow::Result<RootConnection> {
-        todo!()
+    async fn try_as_root(&self) -> Result<RootConnection<C>>
+    where
+        C: ChainConfig<AccountId = AccountId>,
+    {
+        RootConnection::try_from_connection(self.as_signed().clone()).await
     }
 }
 
 impl Connection {
-    const DEFAULT_RETRIES: u32 = 10;
-    const RETRY_WAIT_SECS: u64 = 1;
-
     /// Creates new connection from a given url.
     /// By default, it tries to connect 10 times, waiting 1 second between each unsuccessful attempt.
+    /// Use [`Connection::builder`] to customize this behaviour.
     /// * `address` - address in websocket format, e.g. `ws://127.0.0.1:9943`
-    pub async fn new(address: &str) -> Connection {
-        Self::new_with_retries(address, Self::DEFAULT_RETRIES).await
+    pub async fn new(address: &str) -> Result<Connection> {
+        Self::builder(address).build().await
     }
 
-    /// Creates new connection from a given url and given number of connection attempts.
+    /// Creates new connection backed by several nodes of the same chain. Reads are spread over
+    /// all of them, and a failing node is replaced by the next one. See [`EndpointPool`] for
+    /// details.
+    /// * `addresses` - addresses in websocket format, e.g. `ws://127.0.0.1:9943`
+    pub async fn with_endpoints(addresses: &[&str]) -> Result<Connection> {
+        Self::builder_with_endpoints(addresses).build().await
+    }
+
+    /// Returns a builder for a connection to a given url.
     /// * `address` - address in websocket format, e.g. `ws://127.0.0.1:9943`
-    /// * `retries` - number of connection attempts
-    async fn new_with_retries(address: &str, mut retries: u32) -> Connection {
-        loop {
-            let client = OnlineClient::<PolkadotConfig>::from_url(&address).await;
-            match (retries, client) {
-                (_, Ok(client)) => return Connection { client },
-                (0, Err(e)) => panic!("{e:?}"),
-                _ => {
-                    sleep(Duration::from_secs(Self::RETRY_WAIT_SECS));
-                    retries -= 1;
-                }
-            }
-        }
+    pub fn builder(address: &str) -> ConnectionBuilder {
+        Self::builder_with_endpoints(&[address])
+    }
+
+    /// Returns a builder for a connection backed by several nodes of the same chain.
+    /// * `addresses` - addresses in websocket format, e.g. `ws://127.0.0.1:9943`
+    pub fn builder_with_endpoints(addresses: &[&str]) -> ConnectionBuilder {
+        ConnectionBuilder::new(addresses)
     }
+}
 
+impl<C: Config> Connection<C> {
     /// Casts self to the underlying RPC client.
-    pub fn as_client(&self) -> &OnlineClient<PolkadotConfig> {
+    pub fn as_client(&self) -> &OnlineClient<C> {
         &self.client
     }
+
+    /// Returns the hash of the best block known to the node.
+    async fn best_block_hash(&self) -> Result<BlockHash>
+    where
+        C: ChainConfig,
+    {
+        self.retry_request(|| async move {
+            self.client
+                .rpc()
+                .block_hash(None)
+                .await
+                .map_err(Error::from)
+        })
+        .await?
+        .ok_or_else(|| Error::UnexpectedResponse("No best block".to_string()))
+    }
+
+    /// Reads a storage entry under `keys` through the dynamic API, without decoding it. The
+    /// request is retried according to the request retry policy of this connection.
+    pub(crate) async fn dynamic_storage_thunk(
+        &self,
+        pallet: &str,
+        entry: &str,
+        keys: Vec<Value>,
+        at: Option<BlockHash>,
+    ) -> Result<Option<DecodedValueThunk>>
+    where
+        C: ChainConfig,
+    {
+        info!(target: "subxtxt", "accessing storage at {}::{} at block {:?}", pallet, entry, at);
+        let address = &dynamic::storage(pallet, entry, keys);
+        self.retry_request(|| async move {
+            self.client
+                .storage()
+                .fetch(address, at)
+                .await
+                .map_err(Error::from)
+        })
+        .await
+    }
+
+    /// Reads raw values stored under `keys` in a single request, skipping the keys with no value.
+    async fn storage_values(
+        &self,
+        keys: &[StorageKey],
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(StorageKey, Vec<u8>)>>
+    where
+        C: ChainConfig,
+    {
+        let change_sets = self
+            .retry_request(|| async move {
+                self.client
+                    .rpc()
+                    .query_storage_at(keys.iter().map(|key| key.0.as_slice()), at)
+                    .await
+                    .map_err(Error::from)
+            })
+            .await?;
+
+        Ok(change_sets
+            .into_iter()
+            .flat_map(|change_set| change_set.changes)
+            .filter_map(|(key, value)| value.map(|value| (key, value.0)))
+            .collect())
+    }
+
+    /// Runs `request` according to the request retry policy of this connection. Only transport
+    /// errors are retried.
+    pub(crate) async fn retry_request<T, F, Fut>(&self, request: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.request_retry_policy
+            .retry_if(request, |e| matches!(e, Error::Transport(_)))
+            .await
+    }
 }
 
-impl SignedConnection {
+impl<C: ChainConfig> ConnectionBuilder<C> {
+    /// Returns a builder for a connection to a chain described by `C`, backed by one or more of
+    /// its nodes. For [`PolkadotConfig`] chains, [`Connection::builder`] is more convenient.
+    /// * `addresses` - addresses in websocket format, e.g. `ws://127.0.0.1:9943`
+    pub fn new(addresses: &[&str]) -> Self {
+        Self {
+            addresses: addresses.iter().map(|a| a.to_string()).collect(),
+            retry_policy: RetryPolicy::default(),
+            request_retry_policy: RetryPolicy::never(),
+            tx_timeouts: TxTimeouts::default(),
+            auto_reconnect: false,
+            reconnect_hooks: vec![],
+            load_balancing: LoadBalancing::default(),
+            _config: PhantomData,
+        }
+    }
+
+    /// Sets the policy of retrying to connect to the node. Defaults to [`RetryPolicy::default`].
+    pub fn retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy,
+            ..self
+        }
+    }
+
+    /// Sets the policy of retrying storage reads and RPC calls that failed due to a transport
+    /// error. Defaults to [`RetryPolicy::never`].
+    pub fn request_retry_policy(self, request_retry_policy: RetryPolicy) -> Self {
+        Self {
+            request_retry_policy,
+            ..self
+        }
+    }
+
+    /// Sets how long to wait for transactions to be included and finalized. Can be overridden for
+    /// a single transaction with [`PendingTx::with_timeouts`]. Defaults to no timeouts.
+    pub fn tx_timeouts(self, tx_timeouts: TxTimeouts) -> Self {
+        Self {
+            tx_timeouts,
+            ..self
+        }
+    }
+
+    /// Makes the connection survive node restarts: whenever the connection is lost, the
+    /// underlying client reconnects (according to [`Self::retry_policy`]) and renews active
+    /// subscriptions. See [`ReconnectingRpcClient`] for details.
+    pub fn auto_reconnect(self) -> Self {
+        Self {
+            auto_reconnect: true,
+            ..self
+        }
+    }
+
+    /// Registers a callback notified whenever the connection is lost or restored. Makes sense
+    /// only together with [`Self::auto_reconnect`].
+    pub fn on_reconnect<F: Fn(&ReconnectEvent) + Send + Sync + 'static>(self, hook: F) -> Self {
+        let mut reconnect_hooks = self.reconnect_hooks;
+        reconnect_hooks.push(Arc::new(hook));
+        Self {
+            reconnect_hooks,
+            ..self
+        }
+    }
+
+    /// Sets how to choose a node for reads, if there are many. Defaults to
+    /// [`LoadBalancing::RoundRobin`].
+    pub fn load_balancing(self, load_balancing: LoadBalancing) -> Self {
+        Self {
+            load_balancing,
+            ..self
+        }
+    }
+
+    /// Connects to the node(s), retrying according to the configured policy.
+    ///
+    /// With many nodes, the ones that cannot be reached are skipped. It fails only if none of them
+    /// can be reached.
+    pub async fn build(self) -> Result<Connection<C>> {
+        let (client, tx_client) = match self.addresses.as_slice() {
+            [address] if !self.auto_reconnect => {
+                let client = self
+                    .retry_policy
+                    .retry(|| OnlineClient::<C>::from_url(address))
+                    .await?;
+                (client.clone(), client)
+            }
+            [address] => {
+                let rpc = ReconnectingRpcClient::new(
+                    address,
+                    self.retry_policy.clone(),
+                    self.reconnect_hooks.clone(),
+                )
+                .await
+                .map_err(subxt::Error::from)?;
+                let client = OnlineClient::<C>::from_rpc_client(Arc::new(rpc)).await?;
+                (client.clone(), client)
+            }
+            addresses => {
+                let mut endpoints = vec![];
+                let mut last_error = None;
+                for address in addresses {
+                    match self.rpc_client(address).await {
+                        Ok(client) => endpoints.push((address.clone(), client)),
+                        Err(e) => {
+                            warn!(target: "subxtxt", "skipping unreachable endpoint {}: {:?}", address, e);
+                            last_error = Some(e);
+                        }
+                    }
+                }
+                if endpoints.is_empty() {
+                    return Err(last_error
+                        .map(|e| subxt::Error::from(e).into())
+                        .unwrap_or(Error::NoEndpoints));
+                }
+
+                let pool = Arc::new(EndpointPool::new(endpoints, self.load_balancing)?);
+                (
+                    OnlineClient::<C>::from_rpc_client(pool.clone()).await?,
+                    OnlineClient::<C>::from_rpc_client(Arc::new(PrimaryEndpoint(pool))).await?,
+                )
+            }
+        };
+
+        Ok(Connection {
+            client,
+            tx_client,
+            request_retry_policy: self.request_retry_policy,
+            tx_timeouts: self.tx_timeouts,
+        })
+    }
+
+    /// Connects to a single node of a pool.
+    async fn rpc_client(
+        &self,
+        address: &str,
+    ) -> std::result::Result<Arc<dyn RpcClientT>, RpcError> {
+        if self.auto_reconnect {
+            let client = ReconnectingRpcClient::new(
+                address,
+                self.retry_policy.clone(),
+                self.reconnect_hooks.clone(),
+            )
+            .await?;
+            return Ok(Arc::new(client));
+        }
+
+        let client = self.retry_policy.retry(|| ws_client(address)).await?;
+        Ok(Arc::new(client))
+    }
+}
+
+impl<C: ChainConfig> SignedConnection<C> {
+    /// Creates new signed connection from a given url.
+    /// * `address` - address in websocket format, e.g. `ws://127.0.0.1:9943`
+    /// * `signer` - a [`Signer`] of signing account, e.g. a [`KeyPair`](crate::KeyPair)
+    pub async fn new<S: Signer<C> + 'static>(address: &str, signer: S) -> Result<Self> {
+        Ok(Self::from_connection(
+            ConnectionBuilder::new(&[address]).build().await?,
+            signer,
+        ))
+    }
+
     /// Creates new signed connection from existing [`Connection`] object.
     /// * `connection` - existing connection
-    /// * `signer` - a [`KeyPair`] of signing account
-    pub async fn new(address: &str, signer: KeyPair) -> Self {
-        Self::from_connection(Connection::new(address).await, signer)
+    /// * `signer` - a [`Signer`] of signing account, e.g. a [`KeyPair`](crate::KeyPair)
+    pub fn from_connection<S: Signer<C> + 'static>(connection: Connection<C>, signer: S) -> Self {
+        Self::from_shared_signer(connection, Arc::new(signer))
     }
 
-    /// Creates new signed connection from existing [`Connection`] object.
+    /// Creates new signed connection from existing [`Connection`] object, with a signer that may
+    /// be shared with other connections.
     /// * `connection` - existing connection
-    /// * `signer` - a [`KeyPair`] of signing account
-    pub fn from_connection(connection: Connection, signer: KeyPair) -> Self {
-        Self { connection, signer }
+    /// * `signer` - a [`Signer`] of signing account
+    pub fn from_shared_signer(connection: Connection<C>, signer: Arc<dyn Signer<C>>) -> Self {
+        Self {
+            connection,
+            signer,
+            nonce_manager: Arc::new(NonceManager::default()),
+        }
+    }
+
+    /// Returns the [`NonceManager`] used by [`SignedConnectionApi::send_tx_with_nonce`]. It is
+    /// shared by all clones of this connection.
+    pub fn nonce_manager(&self) -> &NonceManager {
+        &self.nonce_manager
     }
 }
+
+impl<C: ChainConfig<AccountId = AccountId>> RootConnection<C> {
+    /// Creates new root connection from a given url.
+    /// * `address` - address in websocket format, e.g. `ws://127.0.0.1:9943`
+    /// * `root` - a [`Signer`] of the sudo account, e.g. a [`KeyPair`](crate::KeyPair)
+    pub async fn new<S: Signer<C> + 'static>(address: &str, root: S) -> Result<Self> {
+        Self::try_from_connection(SignedConnection::new(address, root).await?).await
+    }
+
+    /// Creates new root connection from existing [`SignedConnection`] object.
+    ///
+    /// Reads `Sudo::Key` storage and fails with [`Error::NotSudo`] if it does not match the signer.
+    /// * `connection` - existing connection signed by the sudo account
+    pub async fn try_from_connection(connection: SignedConnection<C>) -> Result<Self> {
+        let sudo = connection
+            .get_storage_entry_maybe(&sudo_key_address(), None)
+            .await?;
+
+        match sudo {
+            Some(sudo) if &sudo == connection.account_id() => Ok(Self { connection }),
+            sudo => Err(Error::NotSudo {
+                account: connection.account_id().clone(),
+                sudo,
+            }),
+        }
+    }
+}
+
+/// Signs `tx` with the signer of `connection`, submits it and starts watching its progress.
+/// If `nonce` is not given, it is fetched from the node.
+async fn submit_and_watch<C, S, Call>(
+    connection: &S,
+    tx: &Call,
+    params: C::TxParams,
+    nonce: Option<u32>,
+) -> Result<TxProgress<C, OnlineClient<C>>>
+where
+    C: ChainConfig,
+    S: AsSigned<C> + Sync,
+    Call: TxPayload + Send + Sync,
+{
+    if let Some(details) = tx.validation_details() {
+        info!(target:"subxtxt", "Sending extrinsic {}.{} with params: {:?}, nonce: {:?}", details.pallet_name, details.call_name, params, nonce);
+    }
+
+    let extrinsic = sign(connection, tx, params, nonce).await?;
+    let client = connection.as_connection().tx_client.clone();
+    let progress = SubmittableExtrinsic::from_bytes(client, extrinsic)
+        .submit_and_watch()
+        .await?;
+
+    Ok(progress)
+}
+
+/// Signs `tx` with the signer of `connection` and returns the encoded extrinsic. If `nonce` is not
+/// given, it is fetched from the node.
+async fn sign<C, S, Call>(
+    connection: &S,
+    tx: &Call,
+    params: C::TxParams,
+    nonce: Option<u32>,
+) -> Result<Vec<u8>>
+where
+    C: ChainConfig,
+    S: AsSigned<C> + Sync,
+    Call: TxPayload + Send + Sync,
+{
+    let client = connection.as_connection().as_client();
+    let signer = connection.as_signed().signer();
+    client.tx().validate(tx)?;
+    let nonce = match nonce {
+        Some(nonce) => nonce,
+        None => {
+            client
+                .rpc()
+                .system_account_next_index(signer.account_id())
+                .await?
+        }
+    };
+
+    let unsigned = unsigned_tx(client, tx, nonce, params)?;
+    let signature = signer.sign(&unsigned.signing_payload()).await?;
+
+    Ok(unsigned
+        .signed_extrinsic::<C>(&signer.address(), &signature)
+        .0)
+}
+
+/// Prepares `tx` for signing by an account with `nonce`, against the runtime `client` is connected
+/// to.
+fn unsigned_tx<C: ChainConfig, Call: TxPayload>(
+    client: &OnlineClient<C>,
+    tx: &Call,
+    nonce: u32,
+    params: C::TxParams,
+) -> Result<UnsignedTx> {
+    let call_data = client.tx().call_data(tx)?;
+    let runtime_version = client.runtime_version();
+
+    Ok(UnsignedTx::new::<C>(
+        call_data,
+        nonce,
+        client.genesis_hash(),
+        runtime_version.spec_version,
+        runtime_version.transaction_version,
+        params,
+    ))
+}
+
+/// Streams raw keys starting with `prefix`, page by page, all at the same block: `at` or, if not
+/// given, the best block at the time of fetching the first page.
+fn key_pages<C: ChainConfig>(
+    connection: &Connection<C>,
+    prefix: Vec<u8>,
+    page_size: u32,
+    at: Option<BlockHash>,
+) -> impl Stream<Item = Result<Vec<StorageKey>>> + Send + '_ {
+    stream::try_unfold(
+        (prefix, at, None::<StorageKey>, false),
+        move |(prefix, at, start_key, done)| async move {
+            if done {
+                return Ok(None);
+            }
+            let at = match at {
+                Some(at) => at,
+                None => connection.best_block_hash().await?,
+            };
+
+            let keys = {
+                let (prefix, start_key) = (&prefix, start_key.as_ref().map(|key| key.0.as_slice()));
+                connection
+                    .retry_request(|| async move {
+                        connection
+                            .as_client()
+                            .storage()
+                            .fetch_keys(prefix, page_size, start_key, Some(at))
+                            .await
+                            .map_err(Error::from)
+                    })
+                    .await?
+            };
+            let done = keys.is_empty() || keys.len() < page_size as usize;
+            let start_key = keys.last().cloned();
+
+            Ok::<_, Error>(Some((keys, (prefix, Some(at), start_key, done))))
+        },
+    )
+}
+
+/// Subscribes to blocks with the given `status`.
+pub(crate) async fn subscribe_blocks<C: ChainConfig>(
+    connection: &Connection<C>,
+    status: BlockStatus,
+) -> Result<BlockStream<C>> {
+    match status {
+        BlockStatus::Best => connection.subscribe_best_blocks().await,
+        BlockStatus::Finalized => connection.subscribe_finalized_blocks().await,
+    }
+}
+
+/// Storage address of `Sudo::Key`, which does not depend on any particular runtime metadata.
+fn sudo_key_address() -> StaticStorageAddress<DecodeStaticType<AccountId>, Yes, (), ()> {
+    StaticStorageAddress::new("Sudo", "Key", vec![], [0; 32]).unvalidated()
+}
@@ -0,0 +1,191 @@
+use parity_scale_codec::Decode;
+use subxt::{
+    error::{DispatchError as SubxtDispatchError, MetadataError, ModuleError},
+    ext::sp_runtime::{DispatchError as RuntimeError, ModuleError as RuntimeModuleError},
+    Metadata,
+};
+
+use crate::{connection::ObservedTxStatus, AccountId, BlockHash, BlockNumber, TxHash, TxStatus};
+
+/// An alias for a result of any fallible operation in this crate.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Any error that can happen while talking to the chain.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The node could not be reached, or it failed to handle a request.
+    #[error("transport error: {0}")]
+    Transport(subxt::Error),
+    /// The node handled a request, but its answer is not usable, e.g. it knows no best block or
+    /// it ended a subscription that should go on. Unlike [`Error::Transport`], it is not retried.
+    #[error("unexpected response: {0}")]
+    UnexpectedResponse(String),
+    /// A connection was requested without any node address.
+    #[error("no endpoints given")]
+    NoEndpoints,
+    /// A value returned by the node could not be decoded.
+    #[error("decoding error: {0}")]
+    Decode(#[from] parity_scale_codec::Error),
+    /// There is no value under a storage entry that is expected to be present.
+    #[error("no value stored under {pallet}::{entry}")]
+    MissingStorage {
+        /// Name of the pallet owning the storage entry.
+        pallet: String,
+        /// Name of the storage entry.
+        entry: String,
+    },
+    /// The node does not know a block with the given hash.
+    #[error("block {0:?} not found")]
+    BlockNotFound(BlockHash),
+    /// A transaction was included in a block, but its dispatch failed.
+    #[error("dispatch error: {0}")]
+    Dispatch(#[from] DispatchError),
+    /// The runtime of the node does not match the bindings of this crate, e.g. a call generated
+    /// from the checked-in metadata has another shape in the runtime. It is not retried.
+    #[error("incompatible metadata: {0}")]
+    IncompatibleMetadata(String),
+    /// A key pair could not be created, e.g. from an invalid seed.
+    #[error("invalid key: {0}")]
+    InvalidKey(String),
+    /// A signer failed to sign a payload, e.g. a remote signer could not be reached or returned a
+    /// signature made by another account.
+    #[error("signer error: {0}")]
+    Signer(String),
+    /// A transaction will never be included in a block, e.g. it was dropped from the pool or
+    /// found invalid.
+    #[error("transaction {tx_hash:?} was not included: {reason}")]
+    TxNotIncluded {
+        /// Hash of the transaction.
+        tx_hash: TxHash,
+        /// Why the transaction was not included.
+        reason: String,
+    },
+    /// A transaction did not reach the awaited status in time.
+    #[error(
+        "transaction {tx_hash:?} did not reach {stage:?} in time, last status: {last_status:?}"
+    )]
+    TxTimeout {
+        /// Hash of the transaction.
+        tx_hash: TxHash,
+        /// Status that was awaited.
+        stage: TxStatus,
+        /// Last status reported by the node.
+        last_status: ObservedTxStatus,
+    },
+    /// A mortal transaction did not reach the awaited status before its era ended, so it can no
+    /// longer be included in a block.
+    #[error("transaction {tx_hash:?} expired at block {expired_at} before reaching {stage:?}")]
+    TxExpired {
+        /// Hash of the transaction.
+        tx_hash: TxHash,
+        /// Status that was awaited.
+        stage: TxStatus,
+        /// Number of the first block in which the transaction is no longer valid.
+        expired_at: BlockNumber,
+    },
+    /// An operation did not complete in time.
+    #[error("operation timed out: {0}")]
+    Timeout(String),
+    /// A connection is not signed by the sudo key, but it is required to be.
+    #[error("account {account} is not the sudo account ({sudo:?})")]
+    NotSudo {
+        /// Account that signs the connection.
+        account: AccountId,
+        /// Current sudo account, if there is any.
+        sudo: Option<AccountId>,
+    },
+}
+
+impl From<subxt::Error> for Error {
+    fn from(e: subxt::Error) -> Self {
+        match e {
+            subxt::Error::Codec(e) => Error::Decode(e),
+            subxt::Error::Runtime(e) => Error::Dispatch(e.into()),
+            subxt::Error::Metadata(e @ MetadataError::IncompatibleMetadata) => {
+                Error::IncompatibleMetadata(e.to_string())
+            }
+            e => Error::Transport(e),
+        }
+    }
+}
+
+/// Reason of a failed dispatch, decoded against the runtime metadata.
+///
+/// # Examples
+/// ```ignore
+///     match conn.transfer(dest, amount, TxStatus::InBlock).await {
+///         Err(Error::Dispatch(e)) if e.is("Balances", "InsufficientBalance") => { /* ... */ }
+///         result => { /* ... */ }
+///     }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum DispatchError {
+    /// An error defined by a pallet.
+    #[error("{pallet}::{error}")]
+    Module {
+        /// Name of the pallet, e.g. `Balances`.
+        pallet: String,
+        /// Name of the error variant, e.g. `InsufficientBalance`.
+        error: String,
+        /// Documentation of the error variant.
+        docs: Vec<String>,
+    },
+    /// Any other error, e.g. `BadOrigin` or an arithmetic error.
+    #[error("{0}")]
+    Other(String),
+}
+
+impl DispatchError {
+    /// Checks whether this is the `error` variant defined by `pallet`.
+    pub fn is(&self, pallet: &str, error: &str) -> bool {
+        matches!(self, DispatchError::Module { pallet: p, error: e, .. } if p == pallet && e == error)
+    }
+
+    /// Describes an error returned by the runtime, looking up pallet errors in `metadata`.
+    pub(crate) fn from_runtime(e: RuntimeError, metadata: &Metadata) -> Self {
+        if let RuntimeError::Module(RuntimeModuleError { index, error, .. }) = e {
+            if let Ok(details) = metadata.error(index, error[0]) {
+                return DispatchError::Module {
+                    pallet: details.pallet().to_string(),
+                    error: details.error().to_string(),
+                    docs: details.docs().to_vec(),
+                };
+            }
+        }
+
+        Self::without_metadata(e)
+    }
+
+    /// Describes an error returned by the runtime, without looking up pallet errors.
+    fn without_metadata(e: RuntimeError) -> Self {
+        match e {
+            RuntimeError::Module(RuntimeModuleError { index, error, .. }) => {
+                DispatchError::Other(format!("unknown error {} of pallet {}", error[0], index))
+            }
+            e => DispatchError::Other(<&'static str>::from(e).to_string()),
+        }
+    }
+}
+
+impl From<SubxtDispatchError> for DispatchError {
+    fn from(e: SubxtDispatchError) -> Self {
+        match e {
+            SubxtDispatchError::Module(ModuleError {
+                pallet,
+                error,
+                description,
+                ..
+            }) => DispatchError::Module {
+                pallet,
+                error,
+                docs: description,
+            },
+            // Raw encoding of the runtime error, which subxt decodes only for pallet errors.
+            SubxtDispatchError::Other(bytes) => RuntimeError::decode(&mut bytes.as_slice())
+                .map(Self::without_metadata)
+                .unwrap_or_else(|_| {
+                    DispatchError::Other(SubxtDispatchError::Other(bytes).to_string())
+                }),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use parity_scale_codec::{Decode, Encode, Input};
+use subxt::{
+    ext::{sp_core::Bytes, sp_runtime::ApplyExtrinsicResult},
+    rpc::rpc_params,
+    Metadata,
+};
+
+use crate::{connection::ConnectionApi, Balance, ChainConfig, DispatchError, Result, Weight};
+
+/// Cost of a transaction, as estimated by the runtime.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeEstimate {
+    /// Weight of the transaction.
+    pub weight: Weight,
+    /// Dispatch class of the transaction.
+    pub class: DispatchClass,
+    /// Total fee, excluding tip.
+    pub partial_fee: Balance,
+    /// Breakdown of the fee. `None` for transactions that are free of charge, e.g. unsigned ones.
+    pub inclusion_fee: Option<InclusionFee>,
+}
+
+/// Dispatch class of a transaction, which decides on the block space it can use.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Decode)]
+pub enum DispatchClass {
+    /// A regular transaction.
+    Normal,
+    /// An operational transaction, e.g. sent by the governance.
+    Operational,
+    /// A transaction that must be included in every block, e.g. setting the timestamp.
+    Mandatory,
+}
+
+/// Components of the fee paid for including a transaction in a block.
+#[derive(Clone, Debug, Eq, PartialEq, Decode)]
+pub struct InclusionFee {
+    /// Minimum fee paid for every transaction.
+    pub base_fee: Balance,
+    /// Fee for the length of the encoded transaction.
+    pub len_fee: Balance,
+    /// Fee for the weight of the transaction, adjusted by the current fee multiplier, see
+    /// [`TransactionPaymentApi::get_next_fee_multiplier`](crate::pallets::transaction_payment::TransactionPaymentApi::get_next_fee_multiplier).
+    pub adjusted_weight_fee: Balance,
+}
+
+/// Outcome of a transaction that was run against the current state without being submitted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DryRunOutcome {
+    /// The transaction would be included and dispatched successfully.
+    Success,
+    /// The transaction would be included, but its dispatch would fail.
+    DispatchFailed(DispatchError),
+    /// The transaction would not be included, e.g. due to a stale nonce or funds insufficient to
+    /// pay the fee.
+    Invalid(String),
+}
+
+/// Mirrors `RuntimeDispatchInfo` of pallet transaction payment.
+#[derive(Decode)]
+struct RuntimeDispatchInfo {
+    weight: Weight,
+    class: DispatchClass,
+    partial_fee: Balance,
+}
+
+/// Mirrors `FeeDetails` of pallet transaction payment.
+#[derive(Decode)]
+struct FeeDetails {
+    inclusion_fee: Option<InclusionFee>,
+    /// Not encoded by the runtime either.
+    #[codec(skip)]
+    #[allow(dead_code)]
+    tip: Balance,
+}
+
+/// Decodes a value of `T` that must take up the whole input.
+struct Exact<T>(T);
+
+impl<T: Decode> Decode for Exact<T> {
+    fn decode<I: Input>(input: &mut I) -> std::result::Result<Self, parity_scale_codec::Error> {
+        let value = T::decode(input)?;
+        match input.read_byte() {
+            Ok(_) => Err("Input is not fully consumed".into()),
+            Err(_) => Ok(Self(value)),
+        }
+    }
+}
+
+/// Estimates the fee of a signed `extrinsic` with the `TransactionPaymentApi` runtime API, which
+/// also backs the `payment_queryInfo` RPC.
+pub(crate) async fn estimate_fee<C: ChainConfig, X: ConnectionApi<C>>(
+    connection: &X,
+    extrinsic: &[u8],
+) -> Result<FeeEstimate> {
+    let query = Bytes([extrinsic, &(extrinsic.len() as u32).encode()].concat());
+    let info: RuntimeDispatchInfo = connection
+        .rpc_call(
+            "state_call".to_string(),
+            rpc_params!["TransactionPaymentApi_query_info", query.clone()],
+        )
+        .await?;
+    let Exact(details): Exact<FeeDetails> = connection
+        .rpc_call(
+            "state_call".to_string(),
+            rpc_params!["TransactionPaymentApi_query_fee_details", query],
+        )
+        .await?;
+
+    Ok(FeeEstimate {
+        weight: info.weight,
+        class: info.class,
+        partial_fee: info.partial_fee,
+        inclusion_fee: details.inclusion_fee,
+    })
+}
+
+/// Runs a signed `extrinsic` against the current state with the `system_dryRun` RPC, which
+/// requires the node to expose unsafe RPC methods.
+pub(crate) async fn dry_run<C: ChainConfig, X: ConnectionApi<C>>(
+    connection: &X,
+    extrinsic: &[u8],
+    metadata: &Metadata,
+) -> Result<DryRunOutcome> {
+    let result: ApplyExtrinsicResult = connection
+        .rpc_call(
+            "system_dryRun".to_string(),
+            rpc_params![Bytes(extrinsic.to_vec())],
+        )
+        .await?;
+
+    Ok(match result {
+        Ok(Ok(())) => DryRunOutcome::Success,
+        Ok(Err(e)) => DryRunOutcome::DispatchFailed(DispatchError::from_runtime(e, metadata)),
+        Err(e) => DryRunOutcome::Invalid(<&'static str>::from(e).to_string()),
+    })
+}
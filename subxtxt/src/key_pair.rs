@@ -1,65 +1,204 @@
-use std::str::FromStr;
+use std::{str::FromStr, sync::Arc};
 
-use anyhow::anyhow;
-use subxt::{ext::sp_core::Pair, tx::PairSigner, PolkadotConfig};
+use subxt::{
+    ext::{
+        sp_core::{ecdsa, ed25519, sr25519, Bytes, Pair},
+        sp_runtime::traits::{IdentifyAccount, Verify},
+    },
+    tx::{PairSigner, Signer as SubxtSigner},
+    Config, PolkadotConfig,
+};
 
-use crate::{AccountId, RawKeyPair};
+use crate::{AccountId, ChainConfig, Error, RawKeyPair, Result, Signer, UnsignedTx};
 
-/// Used for signing extrinsic payload
-pub struct KeyPair {
-    inner: PairSigner<PolkadotConfig, RawKeyPair>,
+/// A raw key pair of any of the signature schemes supported by [`KeyPair`].
+#[derive(Clone)]
+pub enum CryptoPair {
+    /// An sr25519 key pair, the default scheme of Substrate chains.
+    Sr25519(sr25519::Pair),
+    /// An ed25519 key pair.
+    Ed25519(ed25519::Pair),
+    /// An ecdsa key pair over secp256k1, as used by Ethereum-compatible accounts.
+    Ecdsa(ecdsa::Pair),
 }
 
-impl Clone for KeyPair {
+/// Used for signing extrinsic payload with a key kept in memory
+pub struct KeyPair<C: Config = PolkadotConfig> {
+    pair: CryptoPair,
+    inner: Arc<dyn SubxtSigner<C> + Send + Sync>,
+    sr25519: Option<Arc<PairSigner<C, RawKeyPair>>>,
+}
+
+impl<C: ChainConfig> Clone for KeyPair<C> {
     fn clone(&self) -> Self {
-        KeyPair::new(self.inner.signer().clone())
+        KeyPair {
+            pair: self.pair.clone(),
+            inner: self.inner.clone(),
+            sr25519: self.sr25519.clone(),
+        }
     }
 }
 
-impl FromStr for KeyPair {
-    type Err = anyhow::Error;
+impl<C: ChainConfig> FromStr for KeyPair<C>
+where
+    C::Signature: From<sr25519::Signature>,
+    <C::Signature as Verify>::Signer:
+        From<sr25519::Public> + IdentifyAccount<AccountId = C::AccountId>,
+    C::AccountId: Into<C::Address>,
+{
+    type Err = Error;
 
-    fn from_str(s: &str) -> anyhow::Result<Self> {
-        let pair = Pair::from_string(s, None)
-            .map_err(|e| anyhow!("Can't create pair from seed value: {:?}", e))?;
-        Ok(KeyPair::new(pair))
+    fn from_str(s: &str) -> Result<Self> {
+        raw_keypair_from_string(s).map(KeyPair::new)
     }
 }
 
-impl KeyPair {
-    /// Constructs a new KeyPair from RawKeyPair
-    pub fn new(keypair: RawKeyPair) -> Self {
+impl<C: ChainConfig> KeyPair<C> {
+    /// Constructs a new sr25519 KeyPair from RawKeyPair
+    pub fn new(keypair: RawKeyPair) -> Self
+    where
+        C::Signature: From<sr25519::Signature>,
+        <C::Signature as Verify>::Signer:
+            From<sr25519::Public> + IdentifyAccount<AccountId = C::AccountId>,
+        C::AccountId: Into<C::Address>,
+    {
+        let signer = Arc::new(PairSigner::<C, _>::new(keypair.clone()));
+        KeyPair {
+            inner: signer.clone(),
+            sr25519: Some(signer),
+            pair: CryptoPair::Sr25519(keypair),
+        }
+    }
+
+    /// Constructs a new KeyPair from an ed25519 key pair
+    pub fn ed25519(keypair: ed25519::Pair) -> Self
+    where
+        C::Signature: From<ed25519::Signature>,
+        <C::Signature as Verify>::Signer:
+            From<ed25519::Public> + IdentifyAccount<AccountId = C::AccountId>,
+        C::AccountId: Into<C::Address>,
+    {
         KeyPair {
-            inner: PairSigner::new(keypair),
+            inner: Arc::new(PairSigner::<C, _>::new(keypair.clone())),
+            sr25519: None,
+            pair: CryptoPair::Ed25519(keypair),
+        }
+    }
+
+    /// Constructs a new KeyPair from an ecdsa key pair
+    pub fn ecdsa(keypair: ecdsa::Pair) -> Self
+    where
+        C::Signature: From<ecdsa::Signature>,
+        <C::Signature as Verify>::Signer:
+            From<ecdsa::Public> + IdentifyAccount<AccountId = C::AccountId>,
+        C::AccountId: Into<C::Address>,
+    {
+        KeyPair {
+            inner: Arc::new(PairSigner::<C, _>::new(keypair.clone())),
+            sr25519: None,
+            pair: CryptoPair::Ecdsa(keypair),
+        }
+    }
+
+    /// Constructs a new KeyPair from a key pair of any supported scheme
+    pub fn from_pair(pair: CryptoPair) -> Self
+    where
+        C::Signature: From<sr25519::Signature> + From<ed25519::Signature> + From<ecdsa::Signature>,
+        <C::Signature as Verify>::Signer: From<sr25519::Public>
+            + From<ed25519::Public>
+            + From<ecdsa::Public>
+            + IdentifyAccount<AccountId = C::AccountId>,
+        C::AccountId: Into<C::Address>,
+    {
+        match pair {
+            CryptoPair::Sr25519(pair) => Self::new(pair),
+            CryptoPair::Ed25519(pair) => Self::ed25519(pair),
+            CryptoPair::Ecdsa(pair) => Self::ecdsa(pair),
+        }
+    }
+
+    /// Returns a reference to the inner sr25519 key pair, `None` for key pairs of other schemes
+    pub fn raw_key_pair(&self) -> Option<&RawKeyPair> {
+        match &self.pair {
+            CryptoPair::Sr25519(pair) => Some(pair),
+            _ => None,
         }
     }
 
-    /// Returns a reference to the inner KeyPair
-    pub fn pair_signer(&self) -> &PairSigner<PolkadotConfig, RawKeyPair> {
-        &self.inner
+    /// Returns a reference to the inner `subxt` signer of an sr25519 key pair
+    ///
+    /// # Panics
+    /// If the key pair is not an sr25519 one, see [`KeyPair::raw_key_pair`].
+    #[deprecated(note = "`KeyPair` implements `Signer`, so it can be used directly")]
+    pub fn pair_signer(&self) -> &PairSigner<C, RawKeyPair> {
+        self.sr25519
+            .as_deref()
+            .expect("Only sr25519 key pairs have a `PairSigner`")
     }
 
-    /// Returns a reference to the inner RawKeyPair
-    pub fn raw_key_pair(&self) -> &RawKeyPair {
-        self.inner.signer()
+    /// Returns a reference to the inner key pair
+    pub fn pair(&self) -> &CryptoPair {
+        &self.pair
     }
 
     /// Returns corresponding AccountId
-    pub fn account_id(&self) -> &AccountId {
+    pub fn account_id(&self) -> &C::AccountId {
         self.inner.account_id()
     }
+
+    /// Signs `tx` without any connection to the chain.
+    ///
+    /// # Returns
+    /// Encoded extrinsic, ready to be submitted with
+    /// [`ConnectionApi::submit_raw_extrinsic`](crate::connection::ConnectionApi::submit_raw_extrinsic).
+    pub fn sign_tx(&self, tx: &UnsignedTx) -> Bytes {
+        let signature = self.inner.sign(&tx.signing_payload());
+        tx.signed_extrinsic::<C>(&self.inner.address(), &signature)
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: ChainConfig> Signer<C> for KeyPair<C> {
+    fn account_id(&self) -> &C::AccountId {
+        self.inner.account_id()
+    }
+
+    fn address(&self) -> C::Address {
+        self.inner.address()
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<C::Signature> {
+        Ok(self.inner.sign(payload))
+    }
 }
 
 /// Converts given seed phrase to a sr25519 [`KeyPair`] object.
 /// * `seed` - a 12 or 24 word seed phrase
-pub fn keypair_from_string(seed: &str) -> KeyPair {
-    KeyPair::new(raw_keypair_from_string(seed))
+pub fn keypair_from_string(seed: &str) -> Result<KeyPair> {
+    raw_keypair_from_string(seed).map(KeyPair::new)
+}
+
+/// Converts given seed phrase to an ed25519 [`KeyPair`] object.
+/// * `seed` - a 12 or 24 word seed phrase
+pub fn ed25519_keypair_from_string(seed: &str) -> Result<KeyPair> {
+    pair_from_string(seed).map(KeyPair::ed25519)
+}
+
+/// Converts given seed phrase to an ecdsa [`KeyPair`] object.
+/// * `seed` - a 12 or 24 word seed phrase
+pub fn ecdsa_keypair_from_string(seed: &str) -> Result<KeyPair> {
+    pair_from_string(seed).map(KeyPair::ecdsa)
 }
 
 /// Converts given seed phrase to a sr25519 [`RawKeyPair`] object.
 /// * `seed` - a 12 or 24 word seed phrase
-pub fn raw_keypair_from_string(seed: &str) -> RawKeyPair {
-    Pair::from_string(seed, None).expect("Can't create pair from seed value")
+pub fn raw_keypair_from_string(seed: &str) -> Result<RawKeyPair> {
+    pair_from_string(seed)
+}
+
+fn pair_from_string<P: Pair>(seed: &str) -> Result<P> {
+    P::from_string(seed, None)
+        .map_err(|e| Error::InvalidKey(format!("Can't create pair from seed value: {e:?}")))
 }
 
 /// Converts a key pair object to `AccountId`.
@@ -4,11 +4,36 @@
 
 use subxt::ext::sp_core::{crypto::AccountId32, sr25519, H256};
 
+#[cfg(feature = "aleph-runtime")]
+pub mod aleph_zero;
+mod blocks;
+mod config;
 pub mod connection;
+mod error;
+mod estimate;
 mod key_pair;
+mod nonce;
+mod offline;
 pub mod pallets;
+mod remote_signer;
+mod retry;
+pub mod rpc;
+mod signer;
+mod storage;
+#[cfg(feature = "substrate-node")]
+pub mod substrate_node;
 
+pub use blocks::*;
+pub use config::*;
+pub use error::*;
+pub use estimate::*;
 pub use key_pair::*;
+pub use nonce::*;
+pub use offline::*;
+pub use remote_signer::*;
+pub use retry::*;
+pub use signer::*;
+pub use storage::*;
 
 /// An alias for a type of a key pair that signs chain transactions.
 pub type RawKeyPair = sr25519::Pair;
@@ -26,7 +51,7 @@ pub type TxHash = H256;
 pub type Balance = u128;
 
 /// When submitting a transaction, wait for given status before proceeding.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum TxStatus {
     /// A tx must be included in some block.
     InBlock,
@@ -37,7 +62,15 @@ pub enum TxStatus {
 }
 
 /// Weight of a transaction.
-#[derive(Clone, Debug, Eq, PartialEq, parity_scale_codec::Decode, parity_scale_codec::Encode)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    parity_scale_codec::Decode,
+    parity_scale_codec::Encode,
+    scale_info::TypeInfo,
+)]
 pub struct Weight {
     /// Execution time coordinate.
     #[codec(compact)]
@@ -0,0 +1,48 @@
+use log::info;
+use tokio::sync::Mutex;
+
+use crate::{connection::AsConnection, ChainConfig, Result};
+
+/// Hands out consecutive nonces of a single account without asking the node every time, so that
+/// many transactions can be submitted quickly, even concurrently.
+///
+/// The first nonce is fetched from the node. After a failure (e.g. a tx rejected by the pool or
+/// dropped from it) the manager should be [resynced](Self::resync), so that the next nonce is
+/// fetched from the node again.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    next: Mutex<Option<u32>>,
+}
+
+impl NonceManager {
+    /// Returns the next unused nonce of `account`.
+    /// * `connection` - connection used to fetch the nonce from the node, if it is not known yet
+    /// * `account` - account whose nonce is managed
+    pub async fn next_nonce<C: ChainConfig, X: AsConnection<C> + Sync>(
+        &self,
+        connection: &X,
+        account: &C::AccountId,
+    ) -> Result<u32> {
+        let mut next = self.next.lock().await;
+        let nonce = match *next {
+            Some(nonce) => nonce,
+            None => {
+                connection
+                    .as_connection()
+                    .as_client()
+                    .rpc()
+                    .system_account_next_index(account)
+                    .await?
+            }
+        };
+        *next = Some(nonce + 1);
+
+        Ok(nonce)
+    }
+
+    /// Forgets the locally tracked nonce, so that the next one is fetched from the node.
+    pub async fn resync(&self) {
+        info!(target: "subxtxt", "resyncing nonce with the node");
+        *self.next.lock().await = None;
+    }
+}
@@ -0,0 +1,119 @@
+use parity_scale_codec::{Compact, Encode};
+use serde::{Deserialize, Serialize};
+use subxt::{
+    ext::sp_core::{blake2_256, Bytes},
+    tx::ExtrinsicParams,
+    Config,
+};
+
+use crate::{BlockHash, ChainConfig};
+
+/// Version of the extrinsic format produced by this crate.
+const EXTRINSIC_VERSION: u8 = 4;
+
+/// Bit of the extrinsic version byte that is set for signed extrinsics.
+pub(crate) const SIGNED_MASK: u8 = 0b1000_0000;
+
+/// A transaction prepared for signing, e.g. on an air-gapped machine. It contains everything that
+/// is needed to sign it and to build the final extrinsic, so that no connection to the chain is
+/// needed until the extrinsic is submitted with
+/// [`ConnectionApi::submit_raw_extrinsic`](crate::connection::ConnectionApi::submit_raw_extrinsic).
+///
+/// Serializes to JSON with binary fields encoded as hex strings.
+///
+/// # Examples
+/// ```ignore
+///     // Online machine.
+///     let unsigned = conn.create_unsigned_tx(tx, nonce, Default::default()).await?;
+///     let json = serde_json::to_string(&unsigned)?;
+///
+///     // Air-gapped machine.
+///     let unsigned: UnsignedTx = serde_json::from_str(&json)?;
+///     let extrinsic = key_pair.sign_tx(&unsigned);
+///
+///     // Online machine, later on.
+///     conn.submit_raw_extrinsic(extrinsic.0).await?.wait_for(TxStatus::InBlock).await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UnsignedTx {
+    /// Encoded call.
+    pub call_data: Bytes,
+    /// Encoded signed extensions included in the extrinsic, e.g. era, nonce and tip.
+    pub extra: Bytes,
+    /// Encoded data of signed extensions that is signed, but not included in the extrinsic, e.g.
+    /// genesis hash and runtime version.
+    pub additional: Bytes,
+    /// Nonce of the signer.
+    pub nonce: u32,
+    /// Hash of the genesis block of the chain.
+    pub genesis_hash: BlockHash,
+    /// Version of the runtime the transaction is valid for.
+    pub spec_version: u32,
+    /// Version of the transaction format the transaction is valid for.
+    pub transaction_version: u32,
+}
+
+impl UnsignedTx {
+    /// Prepares a transaction of a chain described by `C` for signing.
+    /// * `call_data` - encoded call
+    /// * `nonce` - nonce of the account that will sign the transaction
+    /// * `genesis_hash` - hash of the genesis block of the chain
+    /// * `spec_version` - version of the runtime
+    /// * `transaction_version` - version of the transaction format of the runtime
+    /// * `params` - additional tx params, e.g. tip or mortal era
+    pub fn new<C: ChainConfig>(
+        call_data: Vec<u8>,
+        nonce: u32,
+        genesis_hash: BlockHash,
+        spec_version: u32,
+        transaction_version: u32,
+        params: C::TxParams,
+    ) -> Self {
+        let params = <C::ExtrinsicParams as ExtrinsicParams<u32, BlockHash>>::new(
+            spec_version,
+            transaction_version,
+            nonce,
+            genesis_hash,
+            params,
+        );
+        let (mut extra, mut additional) = (vec![], vec![]);
+        params.encode_extra_to(&mut extra);
+        params.encode_additional_to(&mut additional);
+
+        Self {
+            call_data: Bytes(call_data),
+            extra: Bytes(extra),
+            additional: Bytes(additional),
+            nonce,
+            genesis_hash,
+            spec_version,
+            transaction_version,
+        }
+    }
+
+    /// Returns the payload that is signed. As required by the runtime, payloads longer than 256
+    /// bytes are hashed.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        let payload = [&self.call_data.0, &self.extra.0, &self.additional.0].concat();
+        match payload.len() > 256 {
+            true => blake2_256(&payload).to_vec(),
+            false => payload,
+        }
+    }
+
+    /// Returns the encoded extrinsic, signed by `address` with `signature` of the
+    /// [signing payload](Self::signing_payload).
+    pub fn signed_extrinsic<C: Config>(
+        &self,
+        address: &C::Address,
+        signature: &C::Signature,
+    ) -> Bytes {
+        let mut extrinsic = vec![EXTRINSIC_VERSION | SIGNED_MASK];
+        address.encode_to(&mut extrinsic);
+        signature.encode_to(&mut extrinsic);
+        extrinsic.extend_from_slice(&self.extra);
+        extrinsic.extend_from_slice(&self.call_data);
+
+        Bytes([Compact(extrinsic.len() as u32).encode(), extrinsic].concat())
+    }
+}
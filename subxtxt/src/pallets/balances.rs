@@ -1,11 +1,23 @@
-use crate::{connection::TxInfo, AccountId, Balance, BlockHash, TxStatus};
+use subxt::{
+    dynamic::{self, DecodedValueThunk, Value},
+    PolkadotConfig,
+};
+
+use crate::{
+    connection::{ConnectionApi, TxInfo},
+    AccountId, Balance, BlockHash, ChainConfig, Result, TxStatus,
+};
 
 /// Pallet balances read-only API.
 #[async_trait::async_trait]
-pub trait BalanceApi {
+pub trait BalanceApi<C: ChainConfig = PolkadotConfig>: ConnectionApi<C> {
     /// The type of balance lock.
     type BalanceLock: Send;
 
+    /// Decodes the locks of a single account, as read from `Balances::Locks` through the dynamic
+    /// API.
+    fn decode_locks(&self, locks: DecodedValueThunk) -> Result<Vec<Self::BalanceLock>>;
+
     /// API for [`locks`](https://paritytech.github.io/substrate/master/pallet_balances/pallet/struct.Pallet.html#method.locks) call.
     /// * `account` - an account to query locked balance for
     /// * `at` - optional hash of a block to query state from
@@ -13,42 +25,47 @@ pub trait BalanceApi {
         &self,
         account: AccountId,
         at: Option<BlockHash>,
-    ) -> Vec<Self::BalanceLock>;
+    ) -> Result<Vec<Self::BalanceLock>>;
 
     /// API for [`locks`](https://paritytech.github.io/substrate/master/pallet_balances/pallet/struct.Pallet.html#method.locks) call.
     /// * `accounts` - a list of accounts to query locked balance for
     /// * `at` - optional hash of a block to query state from
     ///
-    /// By default, this calls `locks_for_account` for each account in `accounts`.
+    ///
+    /// By default, this reads the locks of all `accounts` in a single
+    /// [`get_storage_entries`](ConnectionApi::get_storage_entries) request and decodes them with
+    /// [`Self::decode_locks`]. The locks are in the order of `accounts`, repeated accounts included.
     async fn locks(
         &self,
         accounts: &[AccountId],
         at: Option<BlockHash>,
-    ) -> Vec<Vec<Self::BalanceLock>> {
-        let mut locks = vec![];
-        for account in accounts {
-            locks.push(self.locks_for_account(account.clone(), at).await);
-        }
-        locks
+    ) -> Result<Vec<Vec<Self::BalanceLock>>> {
+        let addresses = accounts
+            .iter()
+            .map(|account| dynamic::storage("Balances", "Locks", vec![Value::from_bytes(account)]))
+            .collect::<Vec<_>>();
+        self.get_storage_entries(&addresses, at)
+            .await?
+            .into_iter()
+            .map(|locks| match locks {
+                Some(locks) => self.decode_locks(locks),
+                None => Ok(vec![]),
+            })
+            .collect()
     }
 
     /// Returns [`total_issuance`](https://paritytech.github.io/substrate/master/pallet_balances/pallet/type.TotalIssuance.html).
-    async fn total_issuance(&self, at: Option<BlockHash>) -> Balance;
+    async fn total_issuance(&self, at: Option<BlockHash>) -> Result<Balance>;
 
     /// Returns [`existential_deposit`](https://paritytech.github.io/substrate/master/pallet_balances/index.html#terminology).
-    async fn existential_deposit(&self) -> anyhow::Result<Balance>;
+    async fn existential_deposit(&self) -> Result<Balance>;
 }
 
 /// Pallet balances API
 #[async_trait::async_trait]
 pub trait BalanceUserApi {
     /// API for [`transfer`](https://paritytech.github.io/substrate/master/pallet_balances/pallet/struct.Pallet.html#method.transfer) call.
-    async fn transfer(
-        &self,
-        dest: AccountId,
-        amount: Balance,
-        status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    async fn transfer(&self, dest: AccountId, amount: Balance, status: TxStatus) -> Result<TxInfo>;
 
     /// API for [`transfer`](https://paritytech.github.io/substrate/master/pallet_balances/pallet/struct.Pallet.html#method.transfer) call.
     /// Include tip in the tx.
@@ -58,7 +75,7 @@ pub trait BalanceUserApi {
         amount: Balance,
         tip: Balance,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 }
 
 /// Pallet balances logic not directly related to any pallet call.
@@ -83,5 +100,5 @@ pub trait BalanceUserBatchExtApi {
         dest: &[AccountId],
         amount: Balance,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 }
@@ -1,6 +1,8 @@
 use parity_scale_codec::Compact;
 
-use crate::{connection::TxInfo, AccountId, Balance, BlockHash, CodeHash, TxStatus, Weight};
+use crate::{
+    connection::TxInfo, AccountId, Balance, BlockHash, CodeHash, Result, TxStatus, Weight,
+};
 
 /// Arguments to [`ContractRpc::call_and_get`].
 #[derive(parity_scale_codec::Encode)]
@@ -32,7 +34,7 @@ pub trait ContractsApi {
         &self,
         code_hash: CodeHash,
         at: Option<BlockHash>,
-    ) -> Option<Self::OwnerInfo>;
+    ) -> Result<Option<Self::OwnerInfo>>;
 }
 
 /// Pallet contracts api.
@@ -48,7 +50,7 @@ pub trait ContractsUserApi {
         storage_limit: Option<Compact<Balance>>,
         determinism: Self::Determinism,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// API for [`instantiate`](https://paritytech.github.io/substrate/master/pallet_contracts/pallet/struct.Pallet.html#method.instantiate) call.
     #[allow(clippy::too_many_arguments)]
@@ -61,7 +63,7 @@ pub trait ContractsUserApi {
         data: Vec<u8>,
         salt: Vec<u8>,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// API for [`instantiate_with_code`](https://paritytech.github.io/substrate/master/pallet_contracts/pallet/struct.Pallet.html#method.instantiate_with_code) call.
     #[allow(clippy::too_many_arguments)]
@@ -74,7 +76,7 @@ pub trait ContractsUserApi {
         data: Vec<u8>,
         salt: Vec<u8>,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// API for [`call`](https://paritytech.github.io/substrate/master/pallet_contracts/pallet/struct.Pallet.html#method.call) call.
     async fn call(
@@ -85,10 +87,10 @@ pub trait ContractsUserApi {
         storage_limit: Option<Compact<Balance>>,
         data: Vec<u8>,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// API for [`remove_code`](https://paritytech.github.io/substrate/master/pallet_contracts/pallet/struct.Pallet.html#method.remove_code) call.
-    async fn remove_code(&self, code_hash: BlockHash, status: TxStatus) -> anyhow::Result<TxInfo>;
+    async fn remove_code(&self, code_hash: BlockHash, status: TxStatus) -> Result<TxInfo>;
 }
 
 /// RPC for runtime ContractsApi
@@ -98,8 +100,5 @@ pub trait ContractRpc {
     type ContractExecResult: Send;
 
     /// API for [`call`](https://paritytech.github.io/substrate/master/pallet_contracts/trait.ContractsApi.html#method.call) call.
-    async fn call_and_get(
-        &self,
-        args: ContractCallArgs,
-    ) -> anyhow::Result<Self::ContractExecResult>;
+    async fn call_and_get(&self, args: ContractCallArgs) -> Result<Self::ContractExecResult>;
 }
@@ -1,4 +1,4 @@
-use crate::{connection::TxInfo, AccountId, BlockNumber, TxStatus, Weight};
+use crate::{connection::TxInfo, AccountId, BlockNumber, Result, TxStatus, Weight};
 
 /// An alias for a call hash.
 pub type CallHash = [u8; 32];
@@ -26,7 +26,7 @@ pub trait MultisigUserApi {
         other_signatories: Vec<AccountId>,
         call: Self::Call,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// API for [`as_multi`](https://paritytech.github.io/substrate/master/pallet_multisig/pallet/struct.Pallet.html#method.as_multi) call.
     async fn as_multi(
@@ -37,7 +37,7 @@ pub trait MultisigUserApi {
         max_weight: Weight,
         call: Self::Call,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// API for [`approve_as_multi`](https://paritytech.github.io/substrate/master/pallet_multisig/pallet/struct.Pallet.html#method.approve_as_multi) call.
     async fn approve_as_multi(
@@ -48,7 +48,7 @@ pub trait MultisigUserApi {
         max_weight: Weight,
         call_hash: CallHash,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// API for [`cancel_as_multi`](https://paritytech.github.io/substrate/master/pallet_multisig/pallet/struct.Pallet.html#method.cancel_as_multi) call.
     async fn cancel_as_multi(
@@ -58,5 +58,5 @@ pub trait MultisigUserApi {
         timepoint: Timepoint,
         call_hash: CallHash,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 }
@@ -1,4 +1,4 @@
-use crate::{connection::TxInfo, AccountId, BlockHash, TxStatus};
+use crate::{connection::TxInfo, AccountId, BlockHash, BlockStatus, Result, TxStatus, WaitTimeout};
 
 /// Pallet session read-only api.
 #[async_trait::async_trait]
@@ -13,13 +13,25 @@ pub trait SessionApi {
         &self,
         account: AccountId,
         at: Option<BlockHash>,
-    ) -> Option<Self::SessionKeys>;
+    ) -> Result<Option<Self::SessionKeys>>;
 
     /// API for [`current_index`](https://paritytech.github.io/substrate/master/pallet_session/pallet/struct.Pallet.html#method.current_index) call.
-    async fn get_session(&self, at: Option<BlockHash>) -> Self::SessionIndex;
+    async fn get_session(&self, at: Option<BlockHash>) -> Result<Self::SessionIndex>;
 
     /// API for [`validators`](https://paritytech.github.io/substrate/master/pallet_session/pallet/struct.Pallet.html#method.validators) call.
-    async fn get_validators(&self, at: Option<BlockHash>) -> Vec<AccountId>;
+    async fn get_validators(&self, at: Option<BlockHash>) -> Result<Vec<AccountId>>;
+
+    /// Waits until `session` starts, based on `Session::NewSession` events. Returns immediately
+    /// if it has already started in the latest block with the given status.
+    /// * `session` - index of the session to wait for
+    /// * `status` - whether to follow best or finalized blocks
+    /// * `timeout` - how long to wait at most
+    async fn wait_for_session(
+        &self,
+        session: Self::SessionIndex,
+        status: BlockStatus,
+        timeout: WaitTimeout,
+    ) -> Result<()>;
 }
 
 /// Pallet session API.
@@ -29,9 +41,5 @@ pub trait SessionUserApi {
     type SessionKeys;
 
     /// API for [`set_keys`](https://paritytech.github.io/substrate/master/pallet_session/pallet/struct.Pallet.html#method.set_keys) call.
-    async fn set_keys(
-        &self,
-        new_keys: Self::SessionKeys,
-        status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    async fn set_keys(&self, new_keys: Self::SessionKeys, status: TxStatus) -> Result<TxInfo>;
 }
@@ -1,6 +1,8 @@
 use subxt::storage::StorageKey;
 
-use crate::{connection::TxInfo, AccountId, Balance, BlockHash, TxStatus};
+use crate::{
+    connection::TxInfo, AccountId, Balance, BlockHash, BlockStatus, Result, TxStatus, WaitTimeout,
+};
 
 /// Any object that implemnts pallet staking read-only api.
 #[async_trait::async_trait]
@@ -16,27 +18,38 @@ pub trait StakingApi {
 
     /// Returns [`active_era`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.active_era).
     /// * `at` - optional hash of a block to query state from
-    async fn get_active_era(&self, at: Option<BlockHash>) -> Self::EraIndex;
+    async fn get_active_era(&self, at: Option<BlockHash>) -> Result<Self::EraIndex>;
 
     /// Returns [`current_era`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.current_era).
     /// * `at` - optional hash of a block to query state from
-    async fn get_current_era(&self, at: Option<BlockHash>) -> Self::EraIndex;
+    async fn get_current_era(&self, at: Option<BlockHash>) -> Result<Self::EraIndex>;
 
     /// Returns [`bonded`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.bonded) for a given stash account.
     /// * `stash` - a stash account id
     /// * `at` - optional hash of a block to query state from
-    async fn get_bonded(&self, stash: AccountId, at: Option<BlockHash>) -> Option<AccountId>;
+    async fn get_bonded(
+        &self,
+        stash: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<AccountId>>;
 
     /// Returns [`ledger`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.ledger) for a given controller account.
     /// * `controller` - a controller account id
     /// * `at` - optional hash of a block to query state from
-    async fn get_ledger(&self, controller: AccountId, at: Option<BlockHash>)
-        -> Self::StakingLedger;
+    async fn get_ledger(
+        &self,
+        controller: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Self::StakingLedger>;
 
     /// Returns [`eras_validator_reward`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.eras_validator_reward) for a given era.
     /// * `era` - an era index
     /// * `at` - optional hash of a block to query state from
-    async fn get_payout_for_era(&self, era: Self::EraIndex, at: Option<BlockHash>) -> Balance;
+    async fn get_payout_for_era(
+        &self,
+        era: Self::EraIndex,
+        at: Option<BlockHash>,
+    ) -> Result<Balance>;
 
     /// Returns [`eras_stakers`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.eras_stakers) for a given era and account id.
     /// * `era` - an era index
@@ -47,7 +60,7 @@ pub trait StakingApi {
         era: Self::EraIndex,
         account_id: &AccountId,
         at: Option<BlockHash>,
-    ) -> Self::Exposure;
+    ) -> Result<Self::Exposure>;
 
     /// Returns [`eras_reward_points`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.eras_reward_points) for a given era.
     /// * `era` - an era index
@@ -56,14 +69,27 @@ pub trait StakingApi {
         &self,
         era: Self::EraIndex,
         at: Option<BlockHash>,
-    ) -> Option<Self::EraRewardPoints>;
+    ) -> Result<Option<Self::EraRewardPoints>>;
 
     /// Returns [`minimum_validator_count`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.minimum_validator_count).
     /// * `at` - optional hash of a block to query state from
-    async fn get_minimum_validator_count(&self, at: Option<BlockHash>) -> u32;
+    async fn get_minimum_validator_count(&self, at: Option<BlockHash>) -> Result<u32>;
 
     /// Returns [`SessionsPerEra`](https://paritytech.github.io/substrate/master/pallet_staking/trait.Config.html#associatedtype.SessionsPerEra) const.
-    async fn get_session_per_era(&self) -> anyhow::Result<u32>;
+    async fn get_session_per_era(&self) -> Result<u32>;
+
+    /// Waits until `era` becomes active, based on `Staking::EraPaid` events emitted at the end of
+    /// every era. Returns immediately if it is already active in the latest block with the given
+    /// status.
+    /// * `era` - index of the era to wait for
+    /// * `status` - whether to follow best or finalized blocks
+    /// * `timeout` - how long to wait at most
+    async fn wait_for_era(
+        &self,
+        era: Self::EraIndex,
+        status: BlockStatus,
+        timeout: WaitTimeout,
+    ) -> Result<()>;
 }
 
 /// Pallet staking api
@@ -78,14 +104,14 @@ pub trait StakingUserApi {
         initial_stake: Balance,
         controller_id: AccountId,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// API for [`validate`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.validate) call.
     async fn validate(
         &self,
         validator_commission_percentage: u8,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// API for [`payout_stakers`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.payout_stakers) call.
     async fn payout_stakers(
@@ -93,24 +119,16 @@ pub trait StakingUserApi {
         stash_account: AccountId,
         era: Self::EraIndex,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// API for [`nominate`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.nominate) call.
-    async fn nominate(
-        &self,
-        nominee_account_id: AccountId,
-        status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    async fn nominate(&self, nominee_account_id: AccountId, status: TxStatus) -> Result<TxInfo>;
 
     /// API for [`chill`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.chill) call.
-    async fn chill(&self, status: TxStatus) -> anyhow::Result<TxInfo>;
+    async fn chill(&self, status: TxStatus) -> Result<TxInfo>;
 
     /// API for [`bond_extra`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.bond_extra) call.
-    async fn bond_extra_stake(
-        &self,
-        extra_stake: Balance,
-        status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    async fn bond_extra_stake(&self, extra_stake: Balance, status: TxStatus) -> Result<TxInfo>;
 }
 
 /// Pallet staking logic, not directly related to any particular pallet call.
@@ -164,7 +182,7 @@ pub trait StakingApiExt {
         accounts: &[(AccountId, AccountId)],
         stake: Balance,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// Send batch of [`nominate`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.nominate) calls.
     /// * `nominator_nominee_pairs` - a slice of account ids pairs (nominator, nominee)
@@ -176,14 +194,14 @@ pub trait StakingApiExt {
         &self,
         nominator_nominee_pairs: &[(AccountId, AccountId)],
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 }
 
 /// Pallet staking api that requires sudo.
 #[async_trait::async_trait]
 pub trait StakingSudoApi {
     /// API for [`force_new_era`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.force_new_era) call.
-    async fn force_new_era(&self, status: TxStatus) -> anyhow::Result<TxInfo>;
+    async fn force_new_era(&self, status: TxStatus) -> Result<TxInfo>;
 
     /// API for [`set_staking_config`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.set_staking_configs) call.
     async fn set_staking_config(
@@ -193,7 +211,7 @@ pub trait StakingSudoApi {
         max_nominators_count: Option<u32>,
         max_validators_count: Option<u32>,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 }
 
 /// Logic for retrieving raw storage keys or values from a pallet staking.
@@ -219,7 +237,7 @@ pub trait StakingRawApi {
         &self,
         era: Self::EraIndex,
         at: Option<BlockHash>,
-    ) -> anyhow::Result<Vec<StorageKey>>;
+    ) -> Result<Vec<StorageKey>>;
 
     /// Returns encoded [`eras_stakers`](https://paritytech.github.io/substrate/master/pallet_staking/struct.Pallet.html#method.eras_stakers).
     /// storage keys for a given era and given account ids
@@ -231,5 +249,38 @@ pub trait StakingRawApi {
         era: Self::EraIndex,
         accounts: &[AccountId],
         at: Option<BlockHash>,
-    ) -> Vec<StorageKey>;
+    ) -> Result<Vec<StorageKey>>;
+}
+
+/// Raw storage key of `Staking::ErasStakers` entries of `era`. Both keys of the map are hashed
+/// with `Twox64Concat` in FRAME staking.
+#[cfg(any(feature = "aleph-runtime", feature = "substrate-node"))]
+pub(crate) fn eras_stakers_key(era: u32) -> Vec<u8> {
+    use parity_scale_codec::Encode;
+    use subxt::ext::sp_core::{twox_128, twox_64};
+
+    let era = era.encode();
+    [
+        twox_128(b"Staking").as_slice(),
+        &twox_128(b"ErasStakers"),
+        &twox_64(&era),
+        &era,
+    ]
+    .concat()
+}
+
+/// Raw storage keys of `Staking::ErasStakers` entries of `accounts` in `era`.
+#[cfg(any(feature = "aleph-runtime", feature = "substrate-node"))]
+pub(crate) fn eras_stakers_keys(era: u32, accounts: &[AccountId]) -> Vec<StorageKey> {
+    use parity_scale_codec::Encode;
+    use subxt::ext::sp_core::twox_64;
+
+    let prefix = eras_stakers_key(era);
+    accounts
+        .iter()
+        .map(|account| {
+            let account = account.encode();
+            StorageKey([prefix.as_slice(), &twox_64(&account), &account].concat())
+        })
+        .collect()
 }
@@ -1,4 +1,4 @@
-use crate::{connection::TxInfo, AccountId, Balance, BlockHash, TxStatus};
+use crate::{connection::TxInfo, AccountId, Balance, BlockHash, Result, TxStatus};
 
 /// Pallet system read-only api.
 #[async_trait::async_trait]
@@ -6,12 +6,12 @@ pub trait SystemApi {
     /// returns free balance of a given account
     /// * `account` - account id
     /// * `at` - optional hash of a block to query state from
-    async fn get_free_balance(&self, account: AccountId, at: Option<BlockHash>) -> Balance;
+    async fn get_free_balance(&self, account: AccountId, at: Option<BlockHash>) -> Result<Balance>;
 }
 
 /// Pallet system api.
 #[async_trait::async_trait]
 pub trait SystemSudoApi {
     /// API for [`set_code`](https://paritytech.github.io/substrate/master/frame_system/pallet/struct.Pallet.html#method.set_code) call.
-    async fn set_code(&self, code: Vec<u8>, status: TxStatus) -> anyhow::Result<TxInfo>;
+    async fn set_code(&self, code: Vec<u8>, status: TxStatus) -> Result<TxInfo>;
 }
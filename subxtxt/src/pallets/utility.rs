@@ -1,4 +1,4 @@
-use crate::{connection::TxInfo, TxStatus};
+use crate::{connection::TxInfo, Result, TxStatus};
 
 /// Pallet utility api.
 #[async_trait::async_trait]
@@ -7,5 +7,5 @@ pub trait UtilityApi {
     type Call;
 
     /// API for [`batch`](https://paritytech.github.io/substrate/master/pallet_utility/pallet/struct.Pallet.html#method.batch) call.
-    async fn batch_call(&self, calls: Vec<Self::Call>, status: TxStatus) -> anyhow::Result<TxInfo>;
+    async fn batch_call(&self, calls: Vec<Self::Call>, status: TxStatus) -> Result<TxInfo>;
 }
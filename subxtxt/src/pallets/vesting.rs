@@ -1,4 +1,4 @@
-use crate::{connection::TxInfo, AccountId, BlockHash, TxStatus};
+use crate::{connection::TxInfo, AccountId, BlockHash, Result, TxStatus};
 
 /// Read only pallet vesting API.
 #[async_trait::async_trait]
@@ -9,7 +9,11 @@ pub trait VestingApi {
     /// Returns [`VestingInfo`] of the given account.
     /// * `who` - an account id
     /// * `at` - optional hash of a block to query state from
-    async fn get_vesting(&self, who: AccountId, at: Option<BlockHash>) -> Vec<Self::VestingInfo>;
+    async fn get_vesting(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Self::VestingInfo>>;
 }
 
 /// Pallet vesting api.
@@ -19,10 +23,10 @@ pub trait VestingUserApi {
     type VestingInfo;
 
     /// API for [`vest`](https://paritytech.github.io/substrate/master/pallet_vesting/pallet/enum.Call.html#variant.vest) call.
-    async fn vest(&self, status: TxStatus) -> anyhow::Result<TxInfo>;
+    async fn vest(&self, status: TxStatus) -> Result<TxInfo>;
 
     /// API for [`vest_other`](https://paritytech.github.io/substrate/master/pallet_vesting/pallet/enum.Call.html#variant.vest_other) call.
-    async fn vest_other(&self, status: TxStatus, other: AccountId) -> anyhow::Result<TxInfo>;
+    async fn vest_other(&self, status: TxStatus, other: AccountId) -> Result<TxInfo>;
 
     /// API for [`vested_transfer`](https://paritytech.github.io/substrate/master/pallet_vesting/pallet/enum.Call.html#variant.vested_transfer) call.
     async fn vested_transfer(
@@ -30,13 +34,8 @@ pub trait VestingUserApi {
         receiver: AccountId,
         schedule: Self::VestingInfo,
         status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    ) -> Result<TxInfo>;
 
     /// API for [`merge_schedules`](https://paritytech.github.io/substrate/master/pallet_vesting/pallet/enum.Call.html#variant.merge_schedules) call.
-    async fn merge_schedules(
-        &self,
-        idx1: u32,
-        idx2: u32,
-        status: TxStatus,
-    ) -> anyhow::Result<TxInfo>;
+    async fn merge_schedules(&self, idx1: u32, idx2: u32, status: TxStatus) -> Result<TxInfo>;
 }
@@ -0,0 +1,226 @@
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use jsonrpsee::{
+    core::{client::ClientT, params::ObjectParams},
+    http_client::{HttpClient, HttpClientBuilder},
+};
+use parity_scale_codec::Decode;
+use serde::{Deserialize, Serialize};
+use subxt::{ext::sp_core::Bytes, Config, PolkadotConfig};
+#[cfg(unix)]
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::UnixStream,
+};
+
+use crate::{ChainConfig, Error, Result, Signer};
+
+/// Name of the JSON-RPC method served by signing daemons.
+pub const SIGN_PAYLOAD_METHOD: &str = "signer_signPayload";
+
+/// How long a [`RemoteSigner`] waits for a signature by default.
+pub const DEFAULT_SIGNER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Signs transactions by delegating to a separate signing daemon, so that the keys never enter
+/// the memory of this process. Every returned signature is verified against the expected account
+/// before it is used.
+///
+/// # Protocol
+/// The daemon serves a single JSON-RPC 2.0 method, [`SIGN_PAYLOAD_METHOD`], with named params:
+/// * `account_id` - account expected to sign, serialized as by `serde`, e.g. SS58 for
+///   [`AccountId`](crate::AccountId)
+/// * `payload` - `0x`-prefixed hex of the payload to sign, i.e. the
+///   [signing payload](crate::UnsignedTx::signing_payload) of a transaction
+///
+/// The result is `0x`-prefixed hex of the SCALE-encoded signature, e.g. of a `MultiSignature`.
+/// Failures are reported as JSON-RPC errors, whose messages are passed on in [`Error::Signer`].
+///
+/// A signature not received within the timeout (see [`RemoteSigner::with_timeout`]) fails with
+/// [`Error::Signer`].
+///
+/// Over HTTP, every request is a separate `POST`. Over a Unix socket, every request is a single
+/// line of JSON on a new connection, answered with a single line of JSON:
+/// ```text
+/// --> {"jsonrpc":"2.0","id":0,"method":"signer_signPayload","params":{"account_id":"5Grw...","payload":"0x0001..."}}
+/// <-- {"jsonrpc":"2.0","id":0,"result":"0x01d4a1..."}
+/// ```
+///
+/// # Examples
+/// ```ignore
+///     let signer = RemoteSigner::http("http://127.0.0.1:9955", account_id)?;
+///     let conn = SignedConnection::new("ws://127.0.0.1:9943", signer).await?;
+/// ```
+pub struct RemoteSigner<C: Config = PolkadotConfig> {
+    transport: Transport,
+    account_id: C::AccountId,
+    timeout: Duration,
+}
+
+enum Transport {
+    Http(HttpClient),
+    #[cfg(unix)]
+    UnixSocket(PathBuf),
+}
+
+/// Request of the signing protocol, as sent over a Unix socket.
+#[derive(Serialize)]
+struct RpcRequest<'a, A> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'static str,
+    params: SignParams<'a, A>,
+}
+
+#[derive(Serialize)]
+struct SignParams<'a, A> {
+    account_id: &'a A,
+    payload: Bytes,
+}
+
+/// Response of the signing protocol, as received over a Unix socket.
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: Option<Bytes>,
+    error: Option<RpcErrorObject>,
+}
+
+#[derive(Deserialize)]
+struct RpcErrorObject {
+    message: String,
+}
+
+impl<C: ChainConfig> RemoteSigner<C>
+where
+    C::AccountId: Send + Sync,
+{
+    /// Creates a signer of `account_id`, backed by a daemon reachable over HTTP.
+    /// * `url` - address of the daemon, e.g. `http://127.0.0.1:9955`
+    /// * `account_id` - account whose key is held by the daemon
+    pub fn http(url: &str, account_id: C::AccountId) -> Result<Self> {
+        let client = HttpClientBuilder::default()
+            .build(url)
+            .map_err(|e| Error::Signer(format!("Invalid signer url {url}: {e}")))?;
+        Ok(Self::new(Transport::Http(client), account_id))
+    }
+
+    /// Creates a signer of `account_id`, backed by a daemon listening on a Unix socket.
+    /// * `path` - path of the socket
+    /// * `account_id` - account whose key is held by the daemon
+    #[cfg(unix)]
+    pub fn unix_socket(path: impl Into<PathBuf>, account_id: C::AccountId) -> Self {
+        Self::new(Transport::UnixSocket(path.into()), account_id)
+    }
+
+    /// Sets how long to wait for a signature. Defaults to [`DEFAULT_SIGNER_TIMEOUT`].
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    fn new(transport: Transport, account_id: C::AccountId) -> Self {
+        Self {
+            transport,
+            account_id,
+            timeout: DEFAULT_SIGNER_TIMEOUT,
+        }
+    }
+
+    async fn request_signature(&self, payload: &[u8]) -> Result<Bytes> {
+        tokio::time::timeout(self.timeout, self.send_request(payload))
+            .await
+            .unwrap_or_else(|_| {
+                Err(Error::Signer(format!(
+                    "No signature from signer within {:?}",
+                    self.timeout
+                )))
+            })
+    }
+
+    async fn send_request(&self, payload: &[u8]) -> Result<Bytes> {
+        let params = SignParams {
+            account_id: &self.account_id,
+            payload: Bytes(payload.to_vec()),
+        };
+
+        match &self.transport {
+            Transport::Http(client) => {
+                let mut object = ObjectParams::new();
+                object
+                    .insert("account_id", params.account_id)
+                    .map_err(signer_error)?;
+                object
+                    .insert("payload", params.payload)
+                    .map_err(signer_error)?;
+                client
+                    .request(SIGN_PAYLOAD_METHOD, object)
+                    .await
+                    .map_err(signer_error)
+            }
+            #[cfg(unix)]
+            Transport::UnixSocket(path) => unix_socket_request(path, params).await,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: ChainConfig> Signer<C> for RemoteSigner<C>
+where
+    C::AccountId: Send + Sync,
+{
+    fn account_id(&self) -> &C::AccountId {
+        &self.account_id
+    }
+
+    fn address(&self) -> C::Address {
+        C::account_address(&self.account_id)
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<C::Signature> {
+        let signature = self.request_signature(payload).await?;
+        let signature = C::Signature::decode(&mut signature.as_ref())?;
+
+        match C::verify(&signature, payload, &self.account_id) {
+            true => Ok(signature),
+            false => Err(Error::Signer(format!(
+                "Signature does not match account {:?}",
+                self.account_id
+            ))),
+        }
+    }
+}
+
+/// Sends a single request of the signing protocol over a new connection to the socket at `path`.
+#[cfg(unix)]
+async fn unix_socket_request<A: Serialize>(
+    path: &Path,
+    params: SignParams<'_, A>,
+) -> Result<Bytes> {
+    let request = RpcRequest {
+        jsonrpc: "2.0",
+        id: 0,
+        method: SIGN_PAYLOAD_METHOD,
+        params,
+    };
+    let mut line = serde_json::to_vec(&request).map_err(signer_error)?;
+    line.push(b'\n');
+
+    let mut stream = UnixStream::connect(path).await.map_err(signer_error)?;
+    stream.write_all(&line).await.map_err(signer_error)?;
+    let mut response = String::new();
+    BufReader::new(stream)
+        .read_line(&mut response)
+        .await
+        .map_err(signer_error)?;
+
+    let response: RpcResponse = serde_json::from_str(&response).map_err(signer_error)?;
+    match (response.result, response.error) {
+        (Some(signature), _) => Ok(signature),
+        (None, Some(error)) => Err(Error::Signer(error.message)),
+        (None, None) => Err(Error::Signer("Empty response from signer".to_string())),
+    }
+}
+
+fn signer_error(e: impl std::fmt::Display) -> Error {
+    Error::Signer(e.to_string())
+}
@@ -0,0 +1,155 @@
+use std::{fmt::Debug, future::Future, time::Duration};
+
+use log::warn;
+use rand::Rng;
+use tokio::time::Instant;
+
+/// How long to wait between consecutive attempts.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Backoff {
+    /// Wait the same amount of time before every retry.
+    Fixed(Duration),
+    /// Double the waiting time after every failed attempt, starting from `initial`, but never wait
+    /// longer than `max`.
+    Exponential {
+        /// Delay before the first retry.
+        initial: Duration,
+        /// Upper bound for a single delay.
+        max: Duration,
+    },
+}
+
+/// Describes whether and how a fallible operation (e.g. connecting to a node, or an RPC request)
+/// should be retried.
+///
+/// # Examples
+/// ```ignore
+///     let policy = RetryPolicy::exponential(Duration::from_millis(100), Duration::from_secs(5))
+///         .with_max_elapsed(Duration::from_secs(60));
+///     let connection = Connection::builder(address).retry_policy(policy).build().await?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    backoff: Backoff,
+    jitter: bool,
+    max_retries: Option<u32>,
+    max_elapsed: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    /// Retries 10 times, waiting 1 second between each unsuccessful attempt.
+    fn default() -> Self {
+        Self::fixed(Duration::from_secs(1)).with_max_retries(10)
+    }
+}
+
+impl RetryPolicy {
+    /// Never retries, i.e. an operation is attempted exactly once.
+    pub fn never() -> Self {
+        Self::fixed(Duration::ZERO).with_max_retries(0)
+    }
+
+    /// Retries indefinitely, waiting `delay` between attempts.
+    pub fn fixed(delay: Duration) -> Self {
+        Self {
+            backoff: Backoff::Fixed(delay),
+            jitter: false,
+            max_retries: None,
+            max_elapsed: None,
+        }
+    }
+
+    /// Retries indefinitely, with exponentially growing delays between `initial` and `max`.
+    /// Delays are randomized (see [`Self::with_jitter`]), so that many clients do not retry at
+    /// the same time.
+    pub fn exponential(initial: Duration, max: Duration) -> Self {
+        Self {
+            backoff: Backoff::Exponential { initial, max },
+            jitter: true,
+            max_retries: None,
+            max_elapsed: None,
+        }
+    }
+
+    /// Gives up after `max_retries` retries (so after `max_retries + 1` attempts in total).
+    pub fn with_max_retries(self, max_retries: u32) -> Self {
+        Self {
+            max_retries: Some(max_retries),
+            ..self
+        }
+    }
+
+    /// Gives up if the next attempt would start after `max_elapsed` since the first one.
+    pub fn with_max_elapsed(self, max_elapsed: Duration) -> Self {
+        Self {
+            max_elapsed: Some(max_elapsed),
+            ..self
+        }
+    }
+
+    /// Enables or disables randomizing every delay to between half and the whole of its value.
+    pub fn with_jitter(self, jitter: bool) -> Self {
+        Self { jitter, ..self }
+    }
+
+    /// Returns how long to wait before the retry number `retry` (counting from 0).
+    pub fn delay(&self, retry: u32) -> Duration {
+        let delay = match self.backoff {
+            Backoff::Fixed(delay) => delay,
+            Backoff::Exponential { initial, max } => initial
+                .checked_mul(2u32.saturating_pow(retry))
+                .map_or(max, |delay| delay.min(max)),
+        };
+
+        match self.jitter {
+            true => delay.mul_f64(rand::thread_rng().gen_range(0.5..=1.0)),
+            false => delay,
+        }
+    }
+
+    /// Runs `operation` until it succeeds or the policy says to give up, in which case the last
+    /// error is returned. Every error is considered worth retrying.
+    pub async fn retry<T, E, F, Fut>(&self, operation: F) -> Result<T, E>
+    where
+        E: Debug,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        self.retry_if(operation, |_| true).await
+    }
+
+    /// Runs `operation` until it succeeds, it fails with an error for which `should_retry` returns
+    /// `false`, or the policy says to give up. In the latter two cases the last error is returned.
+    pub async fn retry_if<T, E, F, Fut, P>(&self, mut operation: F, should_retry: P) -> Result<T, E>
+    where
+        E: Debug,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        P: Fn(&E) -> bool,
+    {
+        let start = Instant::now();
+        let mut retry = 0;
+
+        loop {
+            let error = match operation().await {
+                Ok(result) => return Ok(result),
+                Err(e) => e,
+            };
+
+            if !should_retry(&error) || self.max_retries.map_or(false, |max| retry >= max) {
+                return Err(error);
+            }
+
+            let delay = self.delay(retry);
+            if let Some(max_elapsed) = self.max_elapsed {
+                if start.elapsed() + delay > max_elapsed {
+                    return Err(error);
+                }
+            }
+
+            warn!(target: "subxtxt", "attempt {} failed with {:?}, retrying in {:?}", retry + 1, error, delay);
+            tokio::time::sleep(delay).await;
+            retry += 1;
+        }
+    }
+}
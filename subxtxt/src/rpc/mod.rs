@@ -0,0 +1,42 @@
+//! Custom RPC clients that can back a [`Connection`](crate::connection::Connection).
+
+use jsonrpsee::{
+    client_transport::ws::{Uri, WsTransportClientBuilder},
+    core::client::{Client, ClientBuilder},
+};
+use subxt::error::RpcError;
+
+mod pool;
+mod reconnecting;
+
+pub(crate) use pool::PrimaryEndpoint;
+pub use pool::{EndpointPool, LoadBalancing};
+pub use reconnecting::{ReconnectEvent, ReconnectHook, ReconnectingRpcClient};
+
+/// Requests and subscriptions that must never be sent again, neither to the same node after
+/// reconnecting nor to another node, since it would have side effects (e.g. submitting the same
+/// transaction twice).
+const SUBMISSION_METHODS: &[&str] = &["author_submitExtrinsic", "author_submitAndWatchExtrinsic"];
+
+/// Whether `method` submits a transaction, so that it must not be sent again after a failure.
+fn is_submission(method: &str) -> bool {
+    SUBMISSION_METHODS.contains(&method)
+}
+
+/// Maximum number of notifications buffered for a single subscription.
+const MAX_NOTIFS_PER_SUBSCRIPTION: usize = 4096;
+
+/// Opens a new WebSocket client to `url`, configured the same way as `subxt` does by default.
+pub(crate) async fn ws_client(url: &str) -> Result<Client, RpcError> {
+    let url: Uri = url
+        .parse()
+        .map_err(|e| RpcError::ClientError(Box::new(e)))?;
+    let (sender, receiver) = WsTransportClientBuilder::default()
+        .build(url)
+        .await
+        .map_err(|e| RpcError::ClientError(Box::new(e)))?;
+
+    Ok(ClientBuilder::default()
+        .max_notifs_per_subscription(MAX_NOTIFS_PER_SUBSCRIPTION)
+        .build_with_tokio(sender, receiver))
+}
@@ -0,0 +1,264 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use log::warn;
+use subxt::{
+    error::RpcError,
+    rpc::{RawValue, RpcClientT, RpcFuture, RpcSubscription},
+};
+
+use crate::{rpc::is_submission, Error, Result};
+
+/// Weight of the newest sample in the moving average of endpoint latency, in percents.
+const LATENCY_SAMPLE_WEIGHT: u64 = 20;
+/// Latency added to the moving average of an endpoint every time it fails, in microseconds.
+const FAILURE_PENALTY_MICROS: u64 = 1_000_000;
+/// Upper bound of the moving average of endpoint latency, in microseconds.
+const MAX_LATENCY_MICROS: u64 = 60 * FAILURE_PENALTY_MICROS;
+
+/// How an [`EndpointPool`] chooses a node for read requests and subscriptions.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum LoadBalancing {
+    /// Use the nodes one after another.
+    #[default]
+    RoundRobin,
+    /// Prefer the node that has been answering the fastest recently.
+    LowestLatency,
+}
+
+/// RPC client spreading requests over several nodes of the same chain.
+///
+/// Reads and subscriptions are routed according to [`LoadBalancing`]. If a node fails to handle
+/// a request for a reason other than the request itself being invalid, the next node is tried.
+///
+/// Transaction submission and watching (`author_*` methods), as well as nonce queries, are pinned
+/// to a single, primary node, so that a transaction is never submitted twice and it is watched
+/// by the node that knows it. The primary node changes only when it fails. A failed submission
+/// is never sent to another node, since the failing node may have accepted it already: the error
+/// is returned instead. A [`Connection`]
+/// backed by a pool also sends all requests made while watching a transaction, e.g. fetching
+/// its block and events, to the primary node.
+///
+/// A failing node is penalized as if it answered slowly, so that [`LoadBalancing::LowestLatency`]
+/// tries it last until it recovers.
+///
+/// [`Connection`]: crate::connection::Connection
+pub struct EndpointPool {
+    endpoints: Vec<Endpoint>,
+    load_balancing: LoadBalancing,
+    next: AtomicUsize,
+    primary: AtomicUsize,
+}
+
+struct Endpoint {
+    url: String,
+    client: Arc<dyn RpcClientT>,
+    /// Moving average of the latency, `0` if not measured yet.
+    latency_micros: AtomicU64,
+}
+
+impl EndpointPool {
+    /// Creates a pool out of already connected clients.
+    /// * `endpoints` - pairs of node address and RPC client connected to it; must not be empty
+    /// * `load_balancing` - how to choose a node for read requests
+    pub fn new(
+        endpoints: Vec<(String, Arc<dyn RpcClientT>)>,
+        load_balancing: LoadBalancing,
+    ) -> Result<Self> {
+        if endpoints.is_empty() {
+            return Err(Error::NoEndpoints);
+        }
+
+        Ok(Self {
+            endpoints: endpoints
+                .into_iter()
+                .map(|(url, client)| Endpoint {
+                    url,
+                    client,
+                    latency_micros: AtomicU64::new(0),
+                })
+                .collect(),
+            load_balancing,
+            next: AtomicUsize::new(0),
+            primary: AtomicUsize::new(0),
+        })
+    }
+
+    /// Returns the addresses of all nodes in the pool.
+    pub fn urls(&self) -> Vec<&str> {
+        self.endpoints.iter().map(|e| e.url.as_str()).collect()
+    }
+
+    /// Returns indices of the endpoints in the order in which they should be tried.
+    fn route(&self, pinned: bool) -> Vec<usize> {
+        let count = self.endpoints.len();
+        let first = match (pinned, self.load_balancing) {
+            (true, _) => self.primary.load(Ordering::Relaxed),
+            (false, LoadBalancing::RoundRobin) => self.next.fetch_add(1, Ordering::Relaxed),
+            (false, LoadBalancing::LowestLatency) => {
+                let mut order = (0..count).collect::<Vec<_>>();
+                order.sort_by_key(|&i| self.endpoints[i].latency_micros.load(Ordering::Relaxed));
+                return order;
+            }
+        };
+
+        (0..count).map(|i| (first + i) % count).collect()
+    }
+
+    fn record_latency(&self, index: usize, start: Instant) {
+        let sample = start.elapsed().as_micros() as u64;
+        let latency = &self.endpoints[index].latency_micros;
+        let average = match latency.load(Ordering::Relaxed) {
+            0 => sample,
+            old => (old * (100 - LATENCY_SAMPLE_WEIGHT) + sample * LATENCY_SAMPLE_WEIGHT) / 100,
+        };
+        latency.store(average.max(1), Ordering::Relaxed);
+    }
+
+    fn record_failure(&self, index: usize, pinned: bool, error: &RpcError) {
+        warn!(target: "subxtxt", "endpoint {} failed: {:?}", self.endpoints[index].url, error);
+        let latency = &self.endpoints[index].latency_micros;
+        let penalized = latency
+            .load(Ordering::Relaxed)
+            .saturating_add(FAILURE_PENALTY_MICROS)
+            .min(MAX_LATENCY_MICROS);
+        latency.store(penalized, Ordering::Relaxed);
+        if pinned {
+            let next = (index + 1) % self.endpoints.len();
+            let _ =
+                self.primary
+                    .compare_exchange(index, next, Ordering::Relaxed, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Methods that must always be handled by the primary node.
+fn is_pinned(method: &str) -> bool {
+    method.starts_with("author_") || method == "system_accountNextIndex"
+}
+
+/// Whether `error` says something about the node rather than about the request (in the latter
+/// case, asking another node would not help).
+fn is_node_failure(error: &RpcError) -> bool {
+    match error {
+        RpcError::ClientError(e) => !matches!(
+            e.downcast_ref::<jsonrpsee::core::Error>(),
+            Some(jsonrpsee::core::Error::Call(_))
+        ),
+        _ => true,
+    }
+}
+
+impl EndpointPool {
+    async fn request(
+        &self,
+        method: &str,
+        params: Option<Box<RawValue>>,
+        pinned: bool,
+    ) -> std::result::Result<Box<RawValue>, RpcError> {
+        let mut last_error = None;
+
+        for index in self.route(pinned) {
+            let start = Instant::now();
+            let result = self.endpoints[index]
+                .client
+                .request_raw(method, params.clone())
+                .await;
+            match result {
+                Err(e) if is_node_failure(&e) => {
+                    self.record_failure(index, pinned, &e);
+                    if is_submission(method) {
+                        return Err(e);
+                    }
+                    last_error = Some(e);
+                }
+                result => {
+                    self.record_latency(index, start);
+                    return result;
+                }
+            }
+        }
+
+        Err(last_error.expect("Endpoint pool is not empty"))
+    }
+
+    async fn subscribe(
+        &self,
+        sub: &str,
+        params: Option<Box<RawValue>>,
+        unsub: &str,
+        pinned: bool,
+    ) -> std::result::Result<RpcSubscription, RpcError> {
+        let mut last_error = None;
+
+        for index in self.route(pinned) {
+            let start = Instant::now();
+            let result = self.endpoints[index]
+                .client
+                .subscribe_raw(sub, params.clone(), unsub)
+                .await;
+            match result {
+                Err(e) if is_node_failure(&e) => {
+                    self.record_failure(index, pinned, &e);
+                    if is_submission(sub) {
+                        return Err(e);
+                    }
+                    last_error = Some(e);
+                }
+                result => {
+                    self.record_latency(index, start);
+                    return result;
+                }
+            }
+        }
+
+        Err(last_error.expect("Endpoint pool is not empty"))
+    }
+}
+
+impl RpcClientT for EndpointPool {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RpcFuture<'a, Box<RawValue>> {
+        Box::pin(self.request(method, params, is_pinned(method)))
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, RpcSubscription> {
+        Box::pin(self.subscribe(sub, params, unsub, is_pinned(sub)))
+    }
+}
+
+/// View of an [`EndpointPool`] that sends every request to the primary node, failing over the
+/// same way as for pinned methods.
+pub(crate) struct PrimaryEndpoint(pub(crate) Arc<EndpointPool>);
+
+impl RpcClientT for PrimaryEndpoint {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RpcFuture<'a, Box<RawValue>> {
+        Box::pin(self.0.request(method, params, true))
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, RpcSubscription> {
+        Box::pin(self.0.subscribe(sub, params, unsub, true))
+    }
+}
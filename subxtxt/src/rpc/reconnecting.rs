@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use futures::{stream, StreamExt};
+use jsonrpsee::core::client::Client;
+use log::{info, warn};
+use subxt::{
+    error::RpcError,
+    rpc::{RawValue, RpcClientT, RpcFuture, RpcSubscription},
+};
+use tokio::sync::RwLock;
+
+use crate::{
+    rpc::{is_submission, ws_client},
+    RetryPolicy,
+};
+
+/// Notification about the state of a [`ReconnectingRpcClient`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReconnectEvent {
+    /// The connection to the node was lost and reconnecting has started.
+    Disconnected {
+        /// Address of the node.
+        url: String,
+    },
+    /// The connection to the node was restored.
+    Reconnected {
+        /// Address of the node.
+        url: String,
+    },
+    /// Reconnecting failed according to the retry policy. The next request will try again.
+    ReconnectFailed {
+        /// Address of the node.
+        url: String,
+        /// Description of the last error.
+        error: String,
+    },
+}
+
+/// A callback invoked on every [`ReconnectEvent`].
+pub type ReconnectHook = Arc<dyn Fn(&ReconnectEvent) + Send + Sync>;
+
+/// WebSocket RPC client that transparently reconnects to the node when the connection is lost.
+///
+/// A request that fails because the connection was closed is sent again once the client has
+/// reconnected. Active subscriptions are renewed on the new connection. Transaction submissions
+/// (`author_submitExtrinsic` and `author_submitAndWatchExtrinsic`) are never sent again, since the
+/// node might have received them before the connection was lost; they fail with an error instead.
+#[derive(Clone)]
+pub struct ReconnectingRpcClient {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    url: String,
+    retry_policy: RetryPolicy,
+    hooks: Vec<ReconnectHook>,
+    client: RwLock<Arc<Client>>,
+}
+
+/// Everything needed to renew a subscription.
+struct SubscriptionState {
+    rpc: ReconnectingRpcClient,
+    client: Arc<Client>,
+    sub: String,
+    params: Option<Box<RawValue>>,
+    unsub: String,
+    subscription: RpcSubscription,
+}
+
+impl ReconnectingRpcClient {
+    /// Connects to the node at `url`.
+    /// * `url` - address in websocket format, e.g. `ws://127.0.0.1:9943`
+    /// * `retry_policy` - how to retry both the initial connection and every reconnection
+    /// * `hooks` - callbacks notified about reconnection
+    pub async fn new(
+        url: &str,
+        retry_policy: RetryPolicy,
+        hooks: Vec<ReconnectHook>,
+    ) -> Result<Self, RpcError> {
+        let client = retry_policy.retry(|| ws_client(url)).await?;
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                url: url.to_string(),
+                retry_policy,
+                hooks,
+                client: RwLock::new(Arc::new(client)),
+            }),
+        })
+    }
+
+    async fn client(&self) -> Arc<Client> {
+        self.inner.client.read().await.clone()
+    }
+
+    /// Replaces `stale` client with a new one, unless it has already been done by someone else.
+    async fn reconnect(&self, stale: &Arc<Client>) -> Result<Arc<Client>, RpcError> {
+        let mut client = self.inner.client.write().await;
+        if !Arc::ptr_eq(&client, stale) {
+            return Ok(client.clone());
+        }
+
+        let url = self.inner.url.clone();
+        warn!(target: "subxtxt", "lost connection to {}, reconnecting", url);
+        self.notify(ReconnectEvent::Disconnected { url: url.clone() });
+
+        match self.inner.retry_policy.retry(|| ws_client(&url)).await {
+            Ok(new_client) => {
+                info!(target: "subxtxt", "reconnected to {}", url);
+                *client = Arc::new(new_client);
+                self.notify(ReconnectEvent::Reconnected { url });
+                Ok(client.clone())
+            }
+            Err(e) => {
+                self.notify(ReconnectEvent::ReconnectFailed {
+                    url,
+                    error: e.to_string(),
+                });
+                Err(e)
+            }
+        }
+    }
+
+    fn notify(&self, event: ReconnectEvent) {
+        for hook in &self.inner.hooks {
+            hook(&event);
+        }
+    }
+
+    /// Subscribes again on a fresh connection, if the connection of `state` has been lost.
+    async fn renew(&self, state: &mut SubscriptionState) -> Result<(), RpcError> {
+        if is_submission(&state.sub) {
+            return Err(RpcError::SubscriptionDropped);
+        }
+
+        let client = self.reconnect(&state.client).await?;
+        state.subscription = client
+            .subscribe_raw(&state.sub, state.params.clone(), &state.unsub)
+            .await?;
+        state.client = client;
+        Ok(())
+    }
+}
+
+impl RpcClientT for ReconnectingRpcClient {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            let client = self.client().await;
+            match client.request_raw(method, params.clone()).await {
+                Err(e) if !client.is_connected() => {
+                    let client = self.reconnect(&client).await?;
+                    if is_submission(method) {
+                        return Err(e);
+                    }
+                    client.request_raw(method, params).await
+                }
+                result => result,
+            }
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, RpcSubscription> {
+        Box::pin(async move {
+            let mut client = self.client().await;
+            let result = client.subscribe_raw(sub, params.clone(), unsub).await;
+            let subscription = match result {
+                Err(e) if !client.is_connected() => {
+                    client = self.reconnect(&client).await?;
+                    if is_submission(sub) {
+                        return Err(e);
+                    }
+                    client.subscribe_raw(sub, params.clone(), unsub).await?
+                }
+                result => result?,
+            };
+
+            let state = SubscriptionState {
+                rpc: self.clone(),
+                client,
+                sub: sub.to_string(),
+                params,
+                unsub: unsub.to_string(),
+                subscription,
+            };
+
+            let renewing = stream::unfold(Some(state), |state| async move {
+                let mut state = state?;
+                loop {
+                    let item = state.subscription.next().await;
+                    match item {
+                        Some(Ok(item)) => return Some((Ok(item), Some(state))),
+                        // The subscription ended or failed for a reason other than lost connection.
+                        item if state.client.is_connected() => {
+                            return item.map(|item| (item, Some(state)))
+                        }
+                        _ => {
+                            let rpc = state.rpc.clone();
+                            if let Err(e) = rpc.renew(&mut state).await {
+                                return Some((Err(e), None));
+                            }
+                        }
+                    }
+                }
+            });
+
+            Ok(renewing.boxed())
+        })
+    }
+}
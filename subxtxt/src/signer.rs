@@ -0,0 +1,34 @@
+use subxt::{Config, PolkadotConfig};
+
+use crate::Result;
+
+/// Signs transactions on behalf of a single account, e.g. with a [`KeyPair`](crate::KeyPair) kept
+/// in memory, or by delegating to a separate process holding the keys.
+///
+/// Signing is asynchronous, so that implementations may talk to other processes or machines.
+///
+/// # Examples
+/// ```ignore
+///     struct HardwareWallet { /* ... */ }
+///
+///     #[async_trait::async_trait]
+///     impl Signer for HardwareWallet {
+///         fn account_id(&self) -> &AccountId { &self.account_id }
+///         fn address(&self) -> MultiAddress<AccountId, u32> { self.account_id.clone().into() }
+///         async fn sign(&self, payload: &[u8]) -> Result<MultiSignature> { /* ... */ }
+///     }
+///
+///     let conn = SignedConnection::new("ws://127.0.0.1:9943", HardwareWallet::open()?).await?;
+/// ```
+#[async_trait::async_trait]
+pub trait Signer<C: Config = PolkadotConfig>: Send + Sync {
+    /// Returns the account on whose behalf transactions are signed.
+    fn account_id(&self) -> &C::AccountId;
+
+    /// Returns the address of the account, as included in signed extrinsics.
+    fn address(&self) -> C::Address;
+
+    /// Signs `payload`, i.e. the [signing payload](crate::UnsignedTx::signing_payload) of a
+    /// transaction.
+    async fn sign(&self, payload: &[u8]) -> Result<C::Signature>;
+}
@@ -0,0 +1,299 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Weak},
+};
+
+use frame_metadata::{StorageEntryType, StorageHasher};
+use futures::{
+    stream::{self, BoxStream},
+    StreamExt,
+};
+use parity_scale_codec::Decode;
+use scale_info::TypeDef;
+use subxt::{
+    ext::{
+        scale_value::scale::decode_as_type,
+        sp_core::{storage::StorageChangeSet, twox_128},
+    },
+    metadata::DecodeWithMetadata,
+    rpc::Subscription,
+    storage::{StorageAddress, StorageKey},
+    Metadata,
+};
+use tokio::sync::Mutex;
+
+use crate::{BlockHash, Error, Result};
+
+/// Number of storage keys fetched in a single request, unless specified otherwise.
+pub const DEFAULT_STORAGE_PAGE_SIZE: u32 = 1000;
+
+/// Changes of a single storage value, as returned by
+/// [`ConnectionApi::subscribe_storage`](crate::connection::ConnectionApi::subscribe_storage):
+/// hashes of the blocks in which the value changed, together with the new value (`None` if the
+/// value was removed).
+pub type StorageChanges<V> = BoxStream<'static, Result<(BlockHash, Option<V>)>>;
+
+/// An entry of a storage map, as returned by
+/// [`ConnectionApi::iter_storage_map`](crate::connection::ConnectionApi::iter_storage_map).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageMapEntry<K, V> {
+    /// Raw storage key of the entry.
+    pub key: StorageKey,
+    /// Decoded keys of the map (a tuple for maps with many keys). `None` if any of the hashers of
+    /// the map does not keep the key in the raw storage key, e.g. `Blake2_128` or `Twox128`.
+    pub map_key: Option<K>,
+    /// Decoded value of the entry.
+    pub value: V,
+}
+
+/// Decodes entries of a single storage map according to the runtime metadata.
+pub(crate) struct StorageMapDecoder {
+    metadata: Metadata,
+    /// Raw key prefix shared by all entries that are iterated over.
+    prefix: Vec<u8>,
+    hashers: Vec<StorageHasher>,
+    key_types: Vec<u32>,
+    value_type: u32,
+}
+
+impl StorageMapDecoder {
+    /// Looks up the storage map addressed by `address` in `metadata`. The address may contain
+    /// some of the keys of the map, which limits the entries to the ones with these keys.
+    pub(crate) fn new<Address: StorageAddress>(
+        metadata: Metadata,
+        address: &Address,
+    ) -> Result<Self> {
+        let entry = metadata
+            .pallet(address.pallet_name())
+            .and_then(|pallet| pallet.storage(address.entry_name()))
+            .map_err(subxt::Error::from)?;
+
+        let (hashers, key_types, value_type) = match &entry.ty {
+            StorageEntryType::Map {
+                hashers,
+                key,
+                value,
+            } => {
+                let key_types = match hashers.len() {
+                    1 => vec![key.id()],
+                    _ => match metadata.types().resolve(key.id()).map(|ty| ty.type_def()) {
+                        Some(TypeDef::Tuple(tuple)) => {
+                            tuple.fields().iter().map(|field| field.id()).collect()
+                        }
+                        _ => vec![],
+                    },
+                };
+                (hashers.clone(), key_types, value.id())
+            }
+            StorageEntryType::Plain(value) => (vec![], vec![], value.id()),
+        };
+
+        let prefix = storage_key(&metadata, address)?;
+
+        Ok(Self {
+            metadata,
+            prefix,
+            hashers,
+            key_types,
+            value_type,
+        })
+    }
+
+    /// Raw key prefix shared by all entries of the map that are iterated over.
+    pub(crate) fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    /// Decodes an entry stored under `key`.
+    pub(crate) fn decode<K: Decode, V: DecodeWithMetadata>(
+        &self,
+        key: StorageKey,
+        value: &[u8],
+    ) -> Result<StorageMapEntry<K, V::Target>> {
+        let map_key = match self.map_key_bytes(&key.0) {
+            Some(bytes) => Some(K::decode(&mut bytes.as_slice())?),
+            None => None,
+        };
+        let value = V::decode_with_metadata(&mut &*value, self.value_type, &self.metadata)?;
+
+        Ok(StorageMapEntry {
+            key,
+            map_key,
+            value,
+        })
+    }
+
+    /// Recovers the encoded map keys from a raw storage key, by stripping the prefix of the
+    /// storage entry and the hashes. Returns `None` if any of the hashers is not transparent.
+    fn map_key_bytes(&self, key: &[u8]) -> Option<Vec<u8>> {
+        if self.hashers.is_empty() || self.hashers.len() != self.key_types.len() {
+            return None;
+        }
+
+        // Pallet and storage entry names are hashed with `Twox128`.
+        let mut cursor = key.get(32..)?;
+        let mut encoded = vec![];
+        for (hasher, key_type) in self.hashers.iter().zip(&self.key_types) {
+            let hash_len = match hasher {
+                StorageHasher::Blake2_128Concat => 16,
+                StorageHasher::Twox64Concat => 8,
+                StorageHasher::Identity => 0,
+                _ => return None,
+            };
+            cursor = cursor.get(hash_len..)?;
+            let key_start = cursor;
+            decode_as_type(&mut cursor, *key_type, self.metadata.types()).ok()?;
+            encoded.extend_from_slice(&key_start[..key_start.len() - cursor.len()]);
+        }
+
+        Some(encoded)
+    }
+}
+
+/// Raw storage key of `address`, including the keys it contains.
+pub(crate) fn storage_key<Address: StorageAddress>(
+    metadata: &Metadata,
+    address: &Address,
+) -> Result<Vec<u8>> {
+    let mut key = [
+        twox_128(address.pallet_name().as_bytes()),
+        twox_128(address.entry_name().as_bytes()),
+    ]
+    .concat();
+    address.append_entry_bytes(metadata, &mut key)?;
+
+    Ok(key)
+}
+
+/// Type id of the values stored under `address`.
+pub(crate) fn value_type<Address: StorageAddress>(
+    metadata: &Metadata,
+    address: &Address,
+) -> Result<u32> {
+    let entry = metadata
+        .pallet(address.pallet_name())
+        .and_then(|pallet| pallet.storage(address.entry_name()))
+        .map_err(subxt::Error::from)?;
+
+    Ok(match &entry.ty {
+        StorageEntryType::Map { value, .. } => value.id(),
+        StorageEntryType::Plain(value) => value.id(),
+    })
+}
+
+/// Decodes a raw `value` stored under `address`.
+pub(crate) fn decode_value<Address: StorageAddress>(
+    metadata: &Metadata,
+    address: &Address,
+    value: &[u8],
+) -> Result<<Address::Target as DecodeWithMetadata>::Target> {
+    Ok(Address::Target::decode_with_metadata(
+        &mut &*value,
+        value_type(metadata, address)?,
+        metadata,
+    )?)
+}
+
+/// Block hash and raw value (`None` if removed) of a single changed storage value.
+type RawChange = (BlockHash, Option<Vec<u8>>);
+
+/// Splits a single storage subscription, covering many keys, into the changes of every key.
+struct StorageDemux {
+    subscription: Subscription<StorageChangeSet<BlockHash>>,
+    keys: Vec<StorageKey>,
+    /// Changes delivered by the subscription, but not yet taken, for every key. Failures of the
+    /// subscription are passed on to the keys other than the one that observed them as messages.
+    pending: Vec<VecDeque<std::result::Result<RawChange, String>>>,
+    /// Liveness of the stream of every key, so that changes are not kept for dropped streams.
+    receivers: Vec<Weak<()>>,
+}
+
+impl StorageDemux {
+    /// Returns the next change of the key at `index`, reading the subscription if there is no
+    /// pending one.
+    async fn next(&mut self, index: usize) -> Option<Result<RawChange>> {
+        loop {
+            if let Some(change) = self.pending[index].pop_front() {
+                return Some(change.map_err(|e| Error::Transport(subxt::Error::Other(e))));
+            }
+
+            let Self {
+                subscription,
+                keys,
+                pending,
+                receivers,
+            } = self;
+            let live = |i: usize| receivers[i].strong_count() > 0;
+            match subscription.next().await? {
+                Ok(change_set) => {
+                    for (key, value) in change_set.changes {
+                        for (i, _) in keys.iter().enumerate().filter(|(_, k)| **k == key) {
+                            if live(i) {
+                                let value = value.as_ref().map(|value| value.0.clone());
+                                pending[i].push_back(Ok((change_set.block, value)));
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    for (i, pending) in pending.iter_mut().enumerate() {
+                        if i != index && live(i) {
+                            pending.push_back(Err(e.to_string()));
+                        }
+                    }
+                    return Some(Err(e.into()));
+                }
+            }
+        }
+    }
+}
+
+/// Splits `subscription`, covering all of `keys`, into a stream of changes for every key, decoded
+/// as values of the corresponding `value_types`.
+pub(crate) fn split_storage_changes<V>(
+    subscription: Subscription<StorageChangeSet<BlockHash>>,
+    keys: Vec<StorageKey>,
+    value_types: Vec<u32>,
+    metadata: Metadata,
+) -> Vec<StorageChanges<V::Target>>
+where
+    V: DecodeWithMetadata + 'static,
+    V::Target: Send,
+{
+    let tokens = keys.iter().map(|_| Arc::new(())).collect::<Vec<_>>();
+    let demux = Arc::new(Mutex::new(StorageDemux {
+        subscription,
+        pending: keys.iter().map(|_| VecDeque::new()).collect(),
+        receivers: tokens.iter().map(Arc::downgrade).collect(),
+        keys,
+    }));
+
+    tokens
+        .into_iter()
+        .zip(value_types)
+        .enumerate()
+        .map(|(index, (token, value_type))| {
+            let (demux, metadata) = (demux.clone(), metadata.clone());
+            stream::unfold(token, move |token| {
+                let (demux, metadata) = (demux.clone(), metadata.clone());
+                async move {
+                    let change = demux.lock().await.next(index).await?;
+                    let change = change.and_then(|(block, value)| {
+                        let value = value
+                            .map(|value| {
+                                V::decode_with_metadata(
+                                    &mut value.as_slice(),
+                                    value_type,
+                                    &metadata,
+                                )
+                            })
+                            .transpose()?;
+                        Ok((block, value))
+                    });
+                    Some((change, token))
+                }
+            })
+            .boxed()
+        })
+        .collect()
+}
@@ -0,0 +1,22 @@
+use subxt::{ext::sp_core::Bytes, rpc::RpcParams};
+
+use crate::{
+    connection::AsConnection,
+    pallets::author::AuthorRpc,
+    substrate_node::{SubstrateConnection, SubstrateRootConnection, SubstrateSignedConnection},
+    Result,
+};
+
+impl_for_connections!(AuthorRpc for SubstrateConnection, SubstrateSignedConnection, SubstrateRootConnection {
+    /// Encoded session keys, since their structure depends on the runtime.
+    type SessionKeys = Bytes;
+
+    async fn author_rotate_keys(&self) -> Result<Self::SessionKeys> {
+        Ok(self
+            .as_connection()
+            .as_client()
+            .rpc()
+            .request("author_rotateKeys", RpcParams::new())
+            .await?)
+    }
+});
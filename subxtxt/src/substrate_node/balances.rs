@@ -0,0 +1,97 @@
+use subxt::{
+    dynamic::{self, DecodedValue, DecodedValueThunk, Value},
+    tx::{PlainTip, PolkadotExtrinsicParamsBuilder},
+};
+
+use crate::{
+    connection::{AsConnection, ConnectionApi, SignedConnectionApi, TxInfo},
+    pallets::balances::{BalanceApi, BalanceUserApi, BalanceUserBatchExtApi},
+    substrate_node::{
+        account, address, call, constant, fetch, into_items, SubstrateConnection,
+        SubstrateNodeConfig, SubstrateRootConnection, SubstrateSignedConnection,
+    },
+    AccountId, Balance, BlockHash, Result, TxStatus,
+};
+
+impl_for_connections!(BalanceApi<SubstrateNodeConfig> for SubstrateConnection, SubstrateSignedConnection, SubstrateRootConnection {
+    type BalanceLock = DecodedValue;
+
+    fn decode_locks(&self, locks: DecodedValueThunk) -> Result<Vec<Self::BalanceLock>> {
+        Ok(into_items(locks.to_value()?))
+    }
+
+    async fn locks_for_account(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Self::BalanceLock>> {
+        let locks = self
+            .get_dynamic_storage("Balances", "Locks", vec![account(&account_id)], at)
+            .await?;
+        Ok(locks.map(into_items).unwrap_or_default())
+    }
+
+    async fn total_issuance(&self, at: Option<BlockHash>) -> Result<Balance> {
+        Ok(fetch(self, "Balances", "TotalIssuance", vec![], at)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn existential_deposit(&self) -> Result<Balance> {
+        constant(self, "Balances", "ExistentialDeposit")
+    }
+});
+
+impl_for_connections!(BalanceUserApi for SubstrateSignedConnection, SubstrateRootConnection {
+    async fn transfer(&self, dest: AccountId, amount: Balance, status: TxStatus) -> Result<TxInfo> {
+        let tx = dynamic::tx("Balances", transfer_call(self), transfer_fields(&dest, amount));
+        self.send_tx(tx, status).await
+    }
+
+    async fn transfer_with_tip(
+        &self,
+        dest: AccountId,
+        amount: Balance,
+        tip: Balance,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx("Balances", transfer_call(self), transfer_fields(&dest, amount));
+        self.send_tx_with_params(
+            tx,
+            PolkadotExtrinsicParamsBuilder::new().tip(PlainTip::new(tip)),
+            status,
+        )
+        .await
+    }
+});
+
+impl_for_connections!(BalanceUserBatchExtApi for SubstrateSignedConnection, SubstrateRootConnection {
+    async fn batch_transfer(
+        &self,
+        dest: &[AccountId],
+        amount: Balance,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let transfer = transfer_call(self);
+        let calls = dest
+            .iter()
+            .map(|dest| call("Balances", transfer, transfer_fields(dest, amount)))
+            .collect::<Vec<_>>();
+        let tx = dynamic::tx("Utility", "batch", vec![Value::unnamed_composite(calls)]);
+        self.send_tx(tx, status).await
+    }
+});
+
+fn transfer_fields(dest: &AccountId, amount: Balance) -> Vec<Value> {
+    vec![address(dest), Value::u128(amount)]
+}
+
+/// Name of the call transferring tokens. Newer runtimes replaced `transfer` with
+/// `transfer_allow_death`.
+fn transfer_call<C: AsConnection<SubstrateNodeConfig>>(connection: &C) -> &'static str {
+    let metadata = connection.as_connection().as_client().metadata();
+    match metadata.call_hash("Balances", "transfer_allow_death") {
+        Ok(_) => "transfer_allow_death",
+        Err(_) => "transfer",
+    }
+}
@@ -0,0 +1,155 @@
+use parity_scale_codec::{Compact, Encode};
+use subxt::{
+    dynamic::{self, DecodedValue, Value},
+    ext::sp_core::Bytes,
+    rpc_params,
+};
+
+use crate::{
+    connection::{AsConnection, ConnectionApi, SignedConnectionApi, TxInfo},
+    pallets::contract::{ContractCallArgs, ContractRpc, ContractsApi, ContractsUserApi},
+    substrate_node::{
+        address, option, weight, SubstrateConnection, SubstrateRootConnection,
+        SubstrateSignedConnection,
+    },
+    AccountId, Balance, BlockHash, CodeHash, Result, TxStatus, Weight,
+};
+
+impl_for_connections!(ContractsApi for SubstrateConnection, SubstrateSignedConnection, SubstrateRootConnection {
+    type OwnerInfo = DecodedValue;
+
+    async fn get_owner_info(
+        &self,
+        code_hash: CodeHash,
+        at: Option<BlockHash>,
+    ) -> Result<Option<Self::OwnerInfo>> {
+        let key = Value::from_bytes(code_hash);
+        self.get_dynamic_storage("Contracts", "OwnerInfoOf", vec![key], at)
+            .await
+    }
+});
+
+impl_for_connections!(ContractsUserApi for SubstrateSignedConnection, SubstrateRootConnection {
+    /// Variant of `pallet_contracts::Determinism`, e.g. `Value::unnamed_variant("Deterministic", [])`.
+    type Determinism = Value;
+
+    async fn upload_code(
+        &self,
+        code: Vec<u8>,
+        storage_limit: Option<Compact<Balance>>,
+        determinism: Self::Determinism,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Contracts",
+            "upload_code",
+            vec![
+                Value::from_bytes(code),
+                storage_limit_value(storage_limit),
+                determinism,
+            ],
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn instantiate(
+        &self,
+        code_hash: CodeHash,
+        balance: Balance,
+        gas_limit: Weight,
+        storage_limit: Option<Compact<Balance>>,
+        data: Vec<u8>,
+        salt: Vec<u8>,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Contracts",
+            "instantiate",
+            vec![
+                Value::u128(balance),
+                weight(&gas_limit),
+                storage_limit_value(storage_limit),
+                Value::from_bytes(code_hash),
+                Value::from_bytes(data),
+                Value::from_bytes(salt),
+            ],
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn instantiate_with_code(
+        &self,
+        code: Vec<u8>,
+        balance: Balance,
+        gas_limit: Weight,
+        storage_limit: Option<Compact<Balance>>,
+        data: Vec<u8>,
+        salt: Vec<u8>,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Contracts",
+            "instantiate_with_code",
+            vec![
+                Value::u128(balance),
+                weight(&gas_limit),
+                storage_limit_value(storage_limit),
+                Value::from_bytes(code),
+                Value::from_bytes(data),
+                Value::from_bytes(salt),
+            ],
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn call(
+        &self,
+        destination: AccountId,
+        balance: Balance,
+        gas_limit: Weight,
+        storage_limit: Option<Compact<Balance>>,
+        data: Vec<u8>,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Contracts",
+            "call",
+            vec![
+                address(&destination),
+                Value::u128(balance),
+                weight(&gas_limit),
+                storage_limit_value(storage_limit),
+                Value::from_bytes(data),
+            ],
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn remove_code(&self, code_hash: BlockHash, status: TxStatus) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Contracts",
+            "remove_code",
+            vec![Value::from_bytes(code_hash)],
+        );
+        self.send_tx(tx, status).await
+    }
+});
+
+impl_for_connections!(ContractRpc for SubstrateConnection, SubstrateSignedConnection, SubstrateRootConnection {
+    /// Encoded `ContractExecResult`, since its structure depends on the runtime.
+    type ContractExecResult = Bytes;
+
+    async fn call_and_get(&self, args: ContractCallArgs) -> Result<Self::ContractExecResult> {
+        let params = rpc_params!["ContractsApi_call", Bytes(args.encode())];
+        Ok(self
+            .as_connection()
+            .as_client()
+            .rpc()
+            .request("state_call", params)
+            .await?)
+    }
+});
+
+fn storage_limit_value(storage_limit: Option<Compact<Balance>>) -> Value {
+    option(storage_limit.map(|limit| Value::u128(limit.0)))
+}
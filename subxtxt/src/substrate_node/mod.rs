@@ -0,0 +1,250 @@
+//! Implementations of the [`pallets`](crate::pallets) traits for vanilla Substrate chains, e.g.
+//! `substrate-node-template` or `kitchensink`.
+//!
+//! With the `substrate-node` feature enabled, connections with [`SubstrateNodeConfig`]
+//! ([`SubstrateConnection`], [`SubstrateSignedConnection`] and [`SubstrateRootConnection`])
+//! implement the traits from [`pallets`](crate::pallets) against the standard FRAME pallets.
+//! Everything goes through the dynamic API of `subxt`, so calls and storage keys are encoded
+//! according to the metadata of the connected node. Runtime-specific types (e.g. session keys or
+//! nested calls) are represented as dynamic [`Value`]s.
+//!
+//! Connections with the default config are not affected, so this feature can be enabled together
+//! with `aleph-runtime`.
+//!
+//! [`TreasureApiExt`](crate::pallets::treasury::TreasureApiExt) is not implemented, since the
+//! treasury payout depends on the inflation model of a particular chain.
+//!
+//! # Examples
+//! ```ignore
+//!     let connection = SubstrateConnection::new("ws://127.0.0.1:9944").await?;
+//!     let issuance = connection.total_issuance(None).await?;
+//! ```
+
+use parity_scale_codec::Decode;
+use scale_info::TypeDef;
+use subxt::{
+    dynamic::{self, DecodedValue, DecodedValueThunk, Value},
+    ext::scale_value::ValueDef,
+    tx::PolkadotExtrinsicParams,
+    Config, PolkadotConfig,
+};
+
+use crate::{
+    connection::{
+        AsConnection, Connection, ConnectionBuilder, RootConnection, SignedConnection,
+        SignedConnectionApi, TxInfo,
+    },
+    AccountId, BlockHash, Error, Result, TxStatus, Weight,
+};
+
+/// Implements a trait with the same items for each of the given connection types. A blanket impl
+/// over [`AsConnection<SubstrateNodeConfig>`] would conflict with the impls of
+/// [`aleph_zero`](crate::aleph_zero), so the connection types are listed explicitly.
+macro_rules! impl_for_connections {
+    ($trait:ident $(<$config:ty>)? for $($connection:ty),+ $items:tt) => {
+        $(
+            #[async_trait::async_trait]
+            impl $trait $(<$config>)? for $connection $items
+        )+
+    };
+}
+
+mod author;
+mod balances;
+mod contract;
+mod multisig;
+mod session;
+mod staking;
+mod system;
+mod transaction_payment;
+mod treasury;
+mod utility;
+mod vesting;
+
+/// [`Config`] of vanilla Substrate chains. It has the same types as [`PolkadotConfig`], but is a
+/// distinct type, so that connections with it implement the [`pallets`](crate::pallets) traits
+/// using the dynamic API.
+pub enum SubstrateNodeConfig {}
+
+impl Config for SubstrateNodeConfig {
+    type Index = <PolkadotConfig as Config>::Index;
+    type BlockNumber = <PolkadotConfig as Config>::BlockNumber;
+    type Hash = <PolkadotConfig as Config>::Hash;
+    type Hashing = <PolkadotConfig as Config>::Hashing;
+    type AccountId = <PolkadotConfig as Config>::AccountId;
+    type Address = <PolkadotConfig as Config>::Address;
+    type Header = <PolkadotConfig as Config>::Header;
+    type Signature = <PolkadotConfig as Config>::Signature;
+    type Extrinsic = <PolkadotConfig as Config>::Extrinsic;
+    type ExtrinsicParams = PolkadotExtrinsicParams<Self>;
+}
+
+/// Connection to a Substrate node.
+pub type SubstrateConnection = Connection<SubstrateNodeConfig>;
+
+/// Signed connection to a Substrate node.
+pub type SubstrateSignedConnection = SignedConnection<SubstrateNodeConfig>;
+
+/// Connection to a Substrate node signed by the sudo account.
+pub type SubstrateRootConnection = RootConnection<SubstrateNodeConfig>;
+
+impl SubstrateConnection {
+    /// Creates new connection to a Substrate node from a given url.
+    /// * `address` - address in websocket format, e.g. `ws://127.0.0.1:9944`
+    pub async fn new(address: &str) -> Result<Self> {
+        Self::builder(address).build().await
+    }
+
+    /// Returns a builder for a connection to a Substrate node.
+    /// * `address` - address in websocket format, e.g. `ws://127.0.0.1:9944`
+    pub fn builder(address: &str) -> ConnectionBuilder<SubstrateNodeConfig> {
+        ConnectionBuilder::new(&[address])
+    }
+}
+
+/// Reads a storage entry under `keys`, retrying like
+/// [`ConnectionApi::get_dynamic_storage`](crate::connection::ConnectionApi::get_dynamic_storage).
+async fn fetch_thunk<C: AsConnection<SubstrateNodeConfig> + Sync>(
+    connection: &C,
+    pallet: &str,
+    entry: &str,
+    keys: Vec<Value>,
+    at: Option<BlockHash>,
+) -> Result<Option<DecodedValueThunk>> {
+    connection
+        .as_connection()
+        .dynamic_storage_thunk(pallet, entry, keys, at)
+        .await
+}
+
+/// Reads a storage entry under `keys` and decodes it as `T`.
+async fn fetch<C: AsConnection<SubstrateNodeConfig> + Sync, T: Decode>(
+    connection: &C,
+    pallet: &str,
+    entry: &str,
+    keys: Vec<Value>,
+    at: Option<BlockHash>,
+) -> Result<Option<T>> {
+    match fetch_thunk(connection, pallet, entry, keys, at).await? {
+        Some(thunk) => Ok(Some(T::decode(&mut thunk.encoded())?)),
+        None => Ok(None),
+    }
+}
+
+/// Reads a storage entry under `keys`, falling back to the default value declared in the runtime
+/// metadata, as FRAME does for `ValueQuery` entries.
+async fn fetch_or_default<C: AsConnection<SubstrateNodeConfig> + Sync>(
+    connection: &C,
+    pallet: &str,
+    entry: &str,
+    keys: Vec<Value>,
+    at: Option<BlockHash>,
+) -> Result<DecodedValue> {
+    let connection = connection.as_connection();
+    let address = &dynamic::storage(pallet, entry, keys);
+    let thunk = connection
+        .retry_request(|| async move {
+            connection
+                .as_client()
+                .storage()
+                .fetch_or_default(address, at)
+                .await
+                .map_err(Error::from)
+        })
+        .await?;
+    Ok(thunk.to_value()?)
+}
+
+/// Names of the arguments of `pallet::call` in the runtime the connection is connected to, empty
+/// if there is no such call. Used to pick the shape of calls that changed between FRAME versions.
+fn call_fields<C: AsConnection<SubstrateNodeConfig>>(
+    connection: &C,
+    pallet: &str,
+    call: &str,
+) -> Vec<String> {
+    let metadata = connection.as_connection().as_client().metadata();
+    let calls = metadata
+        .runtime_metadata()
+        .pallets
+        .iter()
+        .find(|p| p.name == pallet)
+        .and_then(|p| p.calls.as_ref())
+        .and_then(|calls| metadata.types().resolve(calls.ty.id()));
+
+    match calls.map(|calls| calls.type_def()) {
+        Some(TypeDef::Variant(def)) => def
+            .variants()
+            .iter()
+            .find(|variant| variant.name() == call)
+            .map(|variant| {
+                variant
+                    .fields()
+                    .iter()
+                    .filter_map(|field| field.name().cloned())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => vec![],
+    }
+}
+
+/// Items of a dynamically decoded sequence, e.g. of a `Vec` or a `BoundedVec`.
+fn into_items(value: DecodedValue) -> Vec<DecodedValue> {
+    match value.value {
+        ValueDef::Composite(composite) => composite.into_values().collect(),
+        _ => vec![],
+    }
+}
+
+/// Reads a constant of the runtime the connection is connected to.
+fn constant<C: AsConnection<SubstrateNodeConfig>, T: Decode>(
+    connection: &C,
+    pallet: &str,
+    name: &str,
+) -> Result<T> {
+    let thunk = connection
+        .as_connection()
+        .as_client()
+        .constants()
+        .at(&dynamic::constant(pallet, name))?;
+    Ok(T::decode(&mut thunk.encoded())?)
+}
+
+/// A call of the runtime that can be nested in another call, e.g. in `Utility::batch`.
+fn call(pallet: &str, call: &str, fields: Vec<Value>) -> Value {
+    Value::unnamed_variant(pallet, [Value::unnamed_variant(call, fields)])
+}
+
+/// Sends `call` wrapped in `Sudo::sudo`.
+async fn sudo(
+    connection: &SubstrateRootConnection,
+    call: Value,
+    status: TxStatus,
+) -> Result<TxInfo> {
+    connection
+        .send_dynamic_tx("Sudo", "sudo", vec![call], status)
+        .await
+}
+
+fn account(account: &AccountId) -> Value {
+    Value::from_bytes(account)
+}
+
+/// `MultiAddress::Id` of `account`.
+fn address(account: &AccountId) -> Value {
+    Value::unnamed_variant("Id", [Value::from_bytes(account)])
+}
+
+fn option(value: Option<Value>) -> Value {
+    match value {
+        Some(value) => Value::unnamed_variant("Some", [value]),
+        None => Value::unnamed_variant("None", []),
+    }
+}
+
+fn weight(weight: &Weight) -> Value {
+    Value::unnamed_composite([
+        Value::u128(weight.ref_time as u128),
+        Value::u128(weight.proof_size as u128),
+    ])
+}
@@ -0,0 +1,105 @@
+use subxt::dynamic::{self, Value};
+
+use crate::{
+    connection::{SignedConnectionApi, TxInfo},
+    pallets::multisig::{CallHash, MultisigThreshold, MultisigUserApi, Timepoint},
+    substrate_node::{account, option, weight, SubstrateRootConnection, SubstrateSignedConnection},
+    AccountId, Result, TxStatus, Weight,
+};
+
+impl_for_connections!(MultisigUserApi for SubstrateSignedConnection, SubstrateRootConnection {
+    /// A runtime call, e.g. `Value::unnamed_variant("Balances", [transfer_call])`.
+    type Call = Value;
+
+    async fn as_multi_threshold_1(
+        &self,
+        other_signatories: Vec<AccountId>,
+        call: Self::Call,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Multisig",
+            "as_multi_threshold_1",
+            vec![signatories(&other_signatories), call],
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn as_multi(
+        &self,
+        threshold: MultisigThreshold,
+        other_signatories: Vec<AccountId>,
+        timepoint: Option<Timepoint>,
+        max_weight: Weight,
+        call: Self::Call,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Multisig",
+            "as_multi",
+            vec![
+                Value::u128(threshold as u128),
+                signatories(&other_signatories),
+                option(timepoint.as_ref().map(timepoint_value)),
+                call,
+                weight(&max_weight),
+            ],
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn approve_as_multi(
+        &self,
+        threshold: MultisigThreshold,
+        other_signatories: Vec<AccountId>,
+        timepoint: Option<Timepoint>,
+        max_weight: Weight,
+        call_hash: CallHash,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Multisig",
+            "approve_as_multi",
+            vec![
+                Value::u128(threshold as u128),
+                signatories(&other_signatories),
+                option(timepoint.as_ref().map(timepoint_value)),
+                Value::from_bytes(call_hash),
+                weight(&max_weight),
+            ],
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn cancel_as_multi(
+        &self,
+        threshold: MultisigThreshold,
+        other_signatories: Vec<AccountId>,
+        timepoint: Timepoint,
+        call_hash: CallHash,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Multisig",
+            "cancel_as_multi",
+            vec![
+                Value::u128(threshold as u128),
+                signatories(&other_signatories),
+                timepoint_value(&timepoint),
+                Value::from_bytes(call_hash),
+            ],
+        );
+        self.send_tx(tx, status).await
+    }
+});
+
+fn signatories(signatories: &[AccountId]) -> Value {
+    Value::unnamed_composite(signatories.iter().map(account))
+}
+
+fn timepoint_value(timepoint: &Timepoint) -> Value {
+    Value::unnamed_composite([
+        Value::u128(timepoint.height as u128),
+        Value::u128(timepoint.index as u128),
+    ])
+}
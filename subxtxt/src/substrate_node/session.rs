@@ -0,0 +1,78 @@
+use parity_scale_codec::Decode;
+use subxt::{
+    dynamic::{self, DecodedValue, Value},
+    events::StaticEvent,
+};
+
+use crate::{
+    blocks::wait_for_state,
+    connection::{ConnectionApi, SignedConnectionApi, TxInfo},
+    pallets::session::{SessionApi, SessionUserApi},
+    substrate_node::{
+        account, fetch, SubstrateConnection, SubstrateRootConnection, SubstrateSignedConnection,
+    },
+    AccountId, BlockHash, BlockStatus, Result, TxStatus, WaitTimeout,
+};
+
+/// Emitted when a new session starts.
+#[derive(Decode)]
+struct NewSession {
+    session_index: u32,
+}
+
+impl StaticEvent for NewSession {
+    const PALLET: &'static str = "Session";
+    const EVENT: &'static str = "NewSession";
+}
+
+impl_for_connections!(SessionApi for SubstrateConnection, SubstrateSignedConnection, SubstrateRootConnection {
+    type SessionKeys = DecodedValue;
+    type SessionIndex = u32;
+
+    async fn get_next_session_keys(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<Self::SessionKeys>> {
+        self.get_dynamic_storage("Session", "NextKeys", vec![account(&account_id)], at)
+            .await
+    }
+
+    async fn get_session(&self, at: Option<BlockHash>) -> Result<Self::SessionIndex> {
+        Ok(fetch(self, "Session", "CurrentIndex", vec![], at)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn get_validators(&self, at: Option<BlockHash>) -> Result<Vec<AccountId>> {
+        Ok(fetch(self, "Session", "Validators", vec![], at)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn wait_for_session(
+        &self,
+        session: Self::SessionIndex,
+        status: BlockStatus,
+        timeout: WaitTimeout,
+    ) -> Result<()> {
+        wait_for_state(
+            self,
+            |at| async move { self.get_session(at).await.map(|index| index >= session) },
+            |event: &NewSession| event.session_index >= session,
+            status,
+            timeout,
+        )
+        .await
+    }
+});
+
+impl_for_connections!(SessionUserApi for SubstrateSignedConnection, SubstrateRootConnection {
+    /// Session keys of the runtime, e.g. `Value::unnamed_composite([babe, grandpa, ...])`.
+    type SessionKeys = Value;
+
+    async fn set_keys(&self, new_keys: Self::SessionKeys, status: TxStatus) -> Result<TxInfo> {
+        let tx = dynamic::tx("Session", "set_keys", vec![new_keys, Value::from_bytes([])]);
+        self.send_tx(tx, status).await
+    }
+});
@@ -0,0 +1,327 @@
+use futures::TryStreamExt;
+use parity_scale_codec::Decode;
+use subxt::{
+    dynamic::{self, DecodedValue, Value},
+    events::StaticEvent,
+    storage::StorageKey,
+};
+
+use crate::{
+    blocks::wait_for_state,
+    connection::{AsConnection, ConnectionApi, SignedConnectionApi, TxInfo},
+    pallets::staking::{
+        eras_stakers_key, eras_stakers_keys, StakingApi, StakingApiExt, StakingRawApi,
+        StakingSudoApi, StakingUserApi,
+    },
+    substrate_node::{
+        account, address, call, call_fields, constant, fetch, fetch_or_default, sudo,
+        SubstrateConnection, SubstrateNodeConfig, SubstrateRootConnection,
+        SubstrateSignedConnection,
+    },
+    AccountId, Balance, BlockHash, BlockStatus, Error, Result, TxStatus, WaitTimeout,
+    DEFAULT_STORAGE_PAGE_SIZE,
+};
+
+/// An alias for an era index.
+type EraIndex = u32;
+
+/// Emitted when an era ends and its rewards are computed.
+#[derive(Decode)]
+struct EraPaid {
+    era_index: EraIndex,
+    _validator_payout: Balance,
+    _remainder: Balance,
+}
+
+impl StaticEvent for EraPaid {
+    const PALLET: &'static str = "Staking";
+    const EVENT: &'static str = "EraPaid";
+}
+
+impl_for_connections!(StakingApi for SubstrateConnection, SubstrateSignedConnection, SubstrateRootConnection {
+    type EraIndex = EraIndex;
+    type StakingLedger = DecodedValue;
+    type Exposure = DecodedValue;
+    type EraRewardPoints = DecodedValue;
+
+    async fn get_active_era(&self, at: Option<BlockHash>) -> Result<Self::EraIndex> {
+        // Index of the active era is followed by the moment it started.
+        fetch::<_, (EraIndex, Option<u64>)>(self, "Staking", "ActiveEra", vec![], at)
+            .await?
+            .map(|(index, _)| index)
+            .ok_or_else(|| missing("ActiveEra"))
+    }
+
+    async fn get_current_era(&self, at: Option<BlockHash>) -> Result<Self::EraIndex> {
+        fetch(self, "Staking", "CurrentEra", vec![], at)
+            .await?
+            .ok_or_else(|| missing("CurrentEra"))
+    }
+
+    async fn get_bonded(
+        &self,
+        stash: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<AccountId>> {
+        fetch(self, "Staking", "Bonded", vec![account(&stash)], at).await
+    }
+
+    async fn get_ledger(
+        &self,
+        controller: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Self::StakingLedger> {
+        self.get_dynamic_storage("Staking", "Ledger", vec![account(&controller)], at)
+            .await?
+            .ok_or_else(|| missing("Ledger"))
+    }
+
+    async fn get_payout_for_era(
+        &self,
+        era: Self::EraIndex,
+        at: Option<BlockHash>,
+    ) -> Result<Balance> {
+        let keys = vec![Value::u128(era as u128)];
+        fetch(self, "Staking", "ErasValidatorReward", keys, at)
+            .await?
+            .ok_or_else(|| missing("ErasValidatorReward"))
+    }
+
+    async fn get_exposure(
+        &self,
+        era: Self::EraIndex,
+        account_id: &AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Self::Exposure> {
+        // An absent entry stands for an empty exposure, like in FRAME staking.
+        let keys = vec![Value::u128(era as u128), account(account_id)];
+        fetch_or_default(self, "Staking", "ErasStakers", keys, at).await
+    }
+
+    async fn get_era_reward_points(
+        &self,
+        era: Self::EraIndex,
+        at: Option<BlockHash>,
+    ) -> Result<Option<Self::EraRewardPoints>> {
+        let keys = vec![Value::u128(era as u128)];
+        self.get_dynamic_storage("Staking", "ErasRewardPoints", keys, at)
+            .await
+    }
+
+    async fn get_minimum_validator_count(&self, at: Option<BlockHash>) -> Result<u32> {
+        Ok(fetch(self, "Staking", "MinimumValidatorCount", vec![], at)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn get_session_per_era(&self) -> Result<u32> {
+        constant(self, "Staking", "SessionsPerEra")
+    }
+
+    async fn wait_for_era(
+        &self,
+        era: Self::EraIndex,
+        status: BlockStatus,
+        timeout: WaitTimeout,
+    ) -> Result<()> {
+        // The next era becomes active in the same block in which the previous one is paid out.
+        wait_for_state(
+            self,
+            |at| async move { self.get_active_era(at).await.map(|active| active >= era) },
+            |event: &EraPaid| event.era_index + 1 >= era,
+            status,
+            timeout,
+        )
+        .await
+    }
+});
+
+impl_for_connections!(StakingUserApi for SubstrateSignedConnection, SubstrateRootConnection {
+    type EraIndex = EraIndex;
+
+    async fn bond(
+        &self,
+        initial_stake: Balance,
+        controller_id: AccountId,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let controller = bond_takes_controller(self).then_some(&controller_id);
+        let tx = dynamic::tx("Staking", "bond", bond_fields(controller, initial_stake));
+        self.send_tx(tx, status).await
+    }
+
+    async fn validate(
+        &self,
+        validator_commission_percentage: u8,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        // Commission is a `Perbill`.
+        let commission = validator_commission_percentage as u128 * 10_000_000;
+        let prefs = Value::unnamed_composite([Value::u128(commission), Value::bool(false)]);
+        let tx = dynamic::tx("Staking", "validate", vec![prefs]);
+        self.send_tx(tx, status).await
+    }
+
+    async fn payout_stakers(
+        &self,
+        stash_account: AccountId,
+        era: Self::EraIndex,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Staking",
+            "payout_stakers",
+            vec![account(&stash_account), Value::u128(era as u128)],
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn nominate(&self, nominee_account_id: AccountId, status: TxStatus) -> Result<TxInfo> {
+        let tx = dynamic::tx("Staking", "nominate", nominate_fields(&nominee_account_id));
+        self.send_tx(tx, status).await
+    }
+
+    async fn chill(&self, status: TxStatus) -> Result<TxInfo> {
+        self.send_tx(dynamic::tx("Staking", "chill", vec![]), status)
+            .await
+    }
+
+    async fn bond_extra_stake(&self, extra_stake: Balance, status: TxStatus) -> Result<TxInfo> {
+        let tx = dynamic::tx("Staking", "bond_extra", vec![Value::u128(extra_stake)]);
+        self.send_tx(tx, status).await
+    }
+});
+
+#[async_trait::async_trait]
+impl StakingApiExt for SubstrateRootConnection {
+    async fn batch_bond(
+        &self,
+        accounts: &[(AccountId, AccountId)],
+        stake: Balance,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let takes_controller = bond_takes_controller(self);
+        let calls = accounts
+            .iter()
+            .map(|(stash, controller)| {
+                let controller = takes_controller.then_some(controller);
+                sudo_as_call(
+                    stash,
+                    call("Staking", "bond", bond_fields(controller, stake)),
+                )
+            })
+            .collect::<Vec<_>>();
+        let tx = dynamic::tx("Utility", "batch", vec![Value::unnamed_composite(calls)]);
+        self.send_tx(tx, status).await
+    }
+
+    async fn batch_nominate(
+        &self,
+        nominator_nominee_pairs: &[(AccountId, AccountId)],
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let calls = nominator_nominee_pairs
+            .iter()
+            .map(|(nominator, nominee)| {
+                sudo_as_call(
+                    nominator,
+                    call("Staking", "nominate", nominate_fields(nominee)),
+                )
+            })
+            .collect::<Vec<_>>();
+        let tx = dynamic::tx("Utility", "batch", vec![Value::unnamed_composite(calls)]);
+        self.send_tx(tx, status).await
+    }
+}
+
+#[async_trait::async_trait]
+impl StakingSudoApi for SubstrateRootConnection {
+    async fn force_new_era(&self, status: TxStatus) -> Result<TxInfo> {
+        sudo(self, call("Staking", "force_new_era", vec![]), status).await
+    }
+
+    async fn set_staking_config(
+        &self,
+        minimal_nominator_bond: Option<Balance>,
+        minimal_validator_bond: Option<Balance>,
+        max_nominators_count: Option<u32>,
+        max_validators_count: Option<u32>,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let mut ops = vec![
+            config_op(minimal_nominator_bond),
+            config_op(minimal_validator_bond),
+            config_op(max_nominators_count.map(u128::from)),
+            config_op(max_validators_count.map(u128::from)),
+        ];
+        // The remaining options, e.g. chill threshold and minimal commission, are left unchanged.
+        // Their number depends on the version of FRAME staking.
+        let count = call_fields(self, "Staking", "set_staking_configs").len();
+        ops.resize(count.max(ops.len()), config_op(None));
+
+        sudo(self, call("Staking", "set_staking_configs", ops), status).await
+    }
+}
+
+impl_for_connections!(StakingRawApi for SubstrateConnection, SubstrateSignedConnection, SubstrateRootConnection {
+    type EraIndex = EraIndex;
+
+    async fn get_stakers_storage_keys(
+        &self,
+        era: Self::EraIndex,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<StorageKey>> {
+        self.storage_keys_paged(eras_stakers_key(era), DEFAULT_STORAGE_PAGE_SIZE, at)
+            .try_collect()
+            .await
+    }
+
+    async fn get_stakers_storage_keys_from_accounts(
+        &self,
+        era: Self::EraIndex,
+        accounts: &[AccountId],
+        _: Option<BlockHash>,
+    ) -> Result<Vec<StorageKey>> {
+        Ok(eras_stakers_keys(era, accounts))
+    }
+});
+
+fn missing(entry: &str) -> Error {
+    Error::MissingStorage {
+        pallet: "Staking".to_string(),
+        entry: entry.to_string(),
+    }
+}
+
+/// Whether `Staking::bond` takes a controller. Newer runtimes dropped it, making every stash its
+/// own controller.
+fn bond_takes_controller<C: AsConnection<SubstrateNodeConfig>>(connection: &C) -> bool {
+    call_fields(connection, "Staking", "bond")
+        .iter()
+        .any(|field| field == "controller")
+}
+
+/// Arguments of `Staking::bond`, with `controller` only if given.
+fn bond_fields(controller: Option<&AccountId>, value: Balance) -> Vec<Value> {
+    controller
+        .map(address)
+        .into_iter()
+        .chain([Value::u128(value), Value::unnamed_variant("Staked", [])])
+        .collect()
+}
+
+fn nominate_fields(nominee: &AccountId) -> Vec<Value> {
+    vec![Value::unnamed_composite([address(nominee)])]
+}
+
+fn sudo_as_call(who: &AccountId, call_to_dispatch: Value) -> Value {
+    call("Sudo", "sudo_as", vec![address(who), call_to_dispatch])
+}
+
+/// `ConfigOp::Set` if `value` is given, `ConfigOp::Noop` otherwise.
+fn config_op(value: Option<u128>) -> Value {
+    match value {
+        Some(value) => Value::unnamed_variant("Set", [Value::u128(value)]),
+        None => Value::unnamed_variant("Noop", []),
+    }
+}
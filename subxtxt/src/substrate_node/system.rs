@@ -0,0 +1,50 @@
+use subxt::dynamic::{self, Value};
+
+use crate::{
+    connection::{SignedConnectionApi, TxInfo},
+    pallets::system::{SystemApi, SystemSudoApi},
+    substrate_node::{
+        account, call, fetch, weight, SubstrateConnection, SubstrateRootConnection,
+        SubstrateSignedConnection,
+    },
+    AccountId, Balance, BlockHash, Result, TxStatus, Weight,
+};
+
+impl_for_connections!(SystemApi for SubstrateConnection, SubstrateSignedConnection, SubstrateRootConnection {
+    async fn get_free_balance(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Balance> {
+        // Nonce and reference counters are followed by `AccountData`, which starts with the free
+        // balance in every version of pallet balances.
+        let info = fetch::<_, (u32, u32, u32, u32, Balance)>(
+            self,
+            "System",
+            "Account",
+            vec![account(&account_id)],
+            at,
+        )
+        .await?;
+        Ok(info.map_or(0, |(_, _, _, _, free)| free))
+    }
+});
+
+#[async_trait::async_trait]
+impl SystemSudoApi for SubstrateRootConnection {
+    async fn set_code(&self, code: Vec<u8>, status: TxStatus) -> Result<TxInfo> {
+        // Runtime upgrade may take more than a block, so its weight is not checked.
+        let tx = dynamic::tx(
+            "Sudo",
+            "sudo_unchecked_weight",
+            vec![
+                call("System", "set_code", vec![Value::from_bytes(code)]),
+                weight(&Weight {
+                    ref_time: 0,
+                    proof_size: 0,
+                }),
+            ],
+        );
+        self.send_tx(tx, status).await
+    }
+}
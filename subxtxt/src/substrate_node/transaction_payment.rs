@@ -0,0 +1,19 @@
+use subxt::ext::sp_runtime::{FixedPointNumber, FixedU128};
+
+use crate::{
+    pallets::transaction_payment::TransactionPaymentApi,
+    substrate_node::{
+        fetch, SubstrateConnection, SubstrateRootConnection, SubstrateSignedConnection,
+    },
+    BlockHash, Result,
+};
+
+impl_for_connections!(TransactionPaymentApi for SubstrateConnection, SubstrateSignedConnection, SubstrateRootConnection {
+    async fn get_next_fee_multiplier(&self, at: Option<BlockHash>) -> Result<FixedU128> {
+        Ok(
+            fetch(self, "TransactionPayment", "NextFeeMultiplier", vec![], at)
+                .await?
+                .unwrap_or_else(FixedU128::one),
+        )
+    }
+});
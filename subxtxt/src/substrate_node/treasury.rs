@@ -0,0 +1,66 @@
+use subxt::dynamic::{self, Value};
+
+use crate::{
+    connection::{SignedConnectionApi, TxInfo},
+    pallets::treasury::{TreasuryApi, TreasuryUserApi},
+    substrate_node::{
+        address, constant, fetch, SubstrateConnection, SubstrateRootConnection,
+        SubstrateSignedConnection,
+    },
+    AccountId, Balance, BlockHash, Result, TxStatus,
+};
+
+impl_for_connections!(TreasuryApi for SubstrateConnection, SubstrateSignedConnection, SubstrateRootConnection {
+    async fn treasury_account(&self) -> Result<AccountId> {
+        // Equivalent of `PalletId::into_account_truncating`.
+        let pallet_id: [u8; 8] = constant(self, "Treasury", "PalletId")?;
+        let mut account = [0u8; 32];
+        account[..4].copy_from_slice(b"modl");
+        account[4..12].copy_from_slice(&pallet_id);
+        Ok(AccountId::from(account))
+    }
+
+    async fn proposals_count(&self, at: Option<BlockHash>) -> Result<Option<u32>> {
+        fetch(self, "Treasury", "ProposalCount", vec![], at).await
+    }
+
+    async fn approvals(&self, at: Option<BlockHash>) -> Result<Vec<u32>> {
+        Ok(fetch(self, "Treasury", "Approvals", vec![], at)
+            .await?
+            .unwrap_or_default())
+    }
+});
+
+impl_for_connections!(TreasuryUserApi for SubstrateSignedConnection, SubstrateRootConnection {
+    async fn propose_spend(
+        &self,
+        amount: Balance,
+        beneficiary: AccountId,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Treasury",
+            "propose_spend",
+            vec![Value::u128(amount), address(&beneficiary)],
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn approve(&self, proposal_id: u32, status: TxStatus) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Treasury",
+            "approve_proposal",
+            vec![Value::u128(proposal_id as u128)],
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn reject(&self, proposal_id: u32, status: TxStatus) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Treasury",
+            "reject_proposal",
+            vec![Value::u128(proposal_id as u128)],
+        );
+        self.send_tx(tx, status).await
+    }
+});
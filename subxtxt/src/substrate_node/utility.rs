@@ -0,0 +1,18 @@
+use subxt::dynamic::{self, Value};
+
+use crate::{
+    connection::{SignedConnectionApi, TxInfo},
+    pallets::utility::UtilityApi,
+    substrate_node::{SubstrateRootConnection, SubstrateSignedConnection},
+    Result, TxStatus,
+};
+
+impl_for_connections!(UtilityApi for SubstrateSignedConnection, SubstrateRootConnection {
+    /// A runtime call, e.g. `Value::unnamed_variant("Balances", [transfer_call])`.
+    type Call = Value;
+
+    async fn batch_call(&self, calls: Vec<Self::Call>, status: TxStatus) -> Result<TxInfo> {
+        let tx = dynamic::tx("Utility", "batch", vec![Value::unnamed_composite(calls)]);
+        self.send_tx(tx, status).await
+    }
+});
@@ -0,0 +1,64 @@
+use subxt::dynamic::{self, DecodedValue, Value};
+
+use crate::{
+    connection::{ConnectionApi, SignedConnectionApi, TxInfo},
+    pallets::vesting::{VestingApi, VestingUserApi},
+    substrate_node::{
+        account, address, into_items, SubstrateConnection, SubstrateRootConnection,
+        SubstrateSignedConnection,
+    },
+    AccountId, BlockHash, Result, TxStatus,
+};
+
+impl_for_connections!(VestingApi for SubstrateConnection, SubstrateSignedConnection, SubstrateRootConnection {
+    type VestingInfo = DecodedValue;
+
+    async fn get_vesting(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Self::VestingInfo>> {
+        let schedules = self
+            .get_dynamic_storage("Vesting", "Vesting", vec![account(&who)], at)
+            .await?;
+        Ok(schedules.map(into_items).unwrap_or_default())
+    }
+});
+
+impl_for_connections!(VestingUserApi for SubstrateSignedConnection, SubstrateRootConnection {
+    /// Vesting schedule, i.e. `Value::unnamed_composite([locked, per_block, starting_block])`.
+    type VestingInfo = Value;
+
+    async fn vest(&self, status: TxStatus) -> Result<TxInfo> {
+        self.send_tx(dynamic::tx("Vesting", "vest", vec![]), status)
+            .await
+    }
+
+    async fn vest_other(&self, status: TxStatus, other: AccountId) -> Result<TxInfo> {
+        let tx = dynamic::tx("Vesting", "vest_other", vec![address(&other)]);
+        self.send_tx(tx, status).await
+    }
+
+    async fn vested_transfer(
+        &self,
+        receiver: AccountId,
+        schedule: Self::VestingInfo,
+        status: TxStatus,
+    ) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Vesting",
+            "vested_transfer",
+            vec![address(&receiver), schedule],
+        );
+        self.send_tx(tx, status).await
+    }
+
+    async fn merge_schedules(&self, idx1: u32, idx2: u32, status: TxStatus) -> Result<TxInfo> {
+        let tx = dynamic::tx(
+            "Vesting",
+            "merge_schedules",
+            vec![Value::u128(idx1 as u128), Value::u128(idx2 as u128)],
+        );
+        self.send_tx(tx, status).await
+    }
+});
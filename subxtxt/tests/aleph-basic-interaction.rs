@@ -1,6 +1,5 @@
-use std::time::Duration;
-
-use subxtxt::connection::Connection;
+use futures::TryStreamExt;
+use subxtxt::connection::{Connection, ConnectionApi};
 use testcontainers::clients::Cli;
 
 use crate::utils::TestContext;
@@ -12,7 +11,7 @@ async fn connects_to_aleph_node() {
     let docker = Cli::default();
     let context = TestContext::new(&docker);
 
-    let _connection = Connection::new(&context.node_address()).await;
+    let _connection = Connection::new(&context.node_address()).await.unwrap();
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -20,11 +19,12 @@ async fn observes_progress() {
     let docker = Cli::default();
     let context = TestContext::new(&docker);
 
-    let connection = Connection::new(&context.node_address()).await;
+    let connection = Connection::new(&context.node_address()).await.unwrap();
 
-    let old_hash = connection.as_client().rpc().finalized_head().await.unwrap();
-    tokio::time::sleep(Duration::from_secs(3)).await;
-    let new_hash = connection.as_client().rpc().finalized_head().await.unwrap();
+    let mut blocks = connection.subscribe_finalized_blocks().await.unwrap();
+    let old = blocks.try_next().await.unwrap().unwrap();
+    let new = blocks.try_next().await.unwrap().unwrap();
 
-    assert_ne!(old_hash, new_hash);
+    assert_eq!(new.number, old.number + 1);
+    assert_eq!(new.parent_hash, old.hash);
 }
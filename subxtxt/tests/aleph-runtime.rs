@@ -0,0 +1,79 @@
+#![cfg(feature = "aleph-runtime")]
+
+use subxtxt::{
+    aleph_zero::api,
+    connection::{Connection, SignedConnection},
+    keypair_from_string,
+    pallets::{
+        balances::{BalanceApi, BalanceUserApi},
+        session::SessionApi,
+        staking::StakingApi,
+        system::SystemApi,
+    },
+    TxStatus,
+};
+use testcontainers::clients::Cli;
+
+use crate::utils::TestContext;
+
+mod utils;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reads_runtime_storage() {
+    let docker = Cli::default();
+    let context = TestContext::new(&docker);
+    let validator = keypair_from_string("//0").unwrap();
+
+    let connection = Connection::new(&context.node_address()).await.unwrap();
+
+    let validators = connection.get_validators(None).await.unwrap();
+    assert_eq!(validators, vec![validator.account_id().clone()]);
+    assert!(connection.total_issuance(None).await.unwrap() > 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bindings_match_node_metadata() {
+    let docker = Cli::default();
+    let context = TestContext::new(&docker);
+    let validator = keypair_from_string("//0").unwrap();
+
+    let connection = Connection::new(&context.node_address()).await.unwrap();
+
+    connection.existential_deposit().await.unwrap();
+    connection.get_session_per_era().await.unwrap();
+    connection.get_current_era(None).await.unwrap();
+    connection
+        .locks_for_account(validator.account_id().clone(), None)
+        .await
+        .unwrap();
+    connection
+        .get_exposure(0, validator.account_id(), None)
+        .await
+        .unwrap();
+
+    // Every call, storage entry and constant of the generated bindings, not only those used above.
+    api::validate_codegen(connection.as_client()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn transfers_tokens() {
+    let docker = Cli::default();
+    let context = TestContext::new(&docker);
+    let sender = keypair_from_string("//0").unwrap();
+    let receiver = keypair_from_string("//Receiver").unwrap();
+    let amount = 1_000_000_000_000;
+
+    let connection = SignedConnection::new(&context.node_address(), sender)
+        .await
+        .unwrap();
+    connection
+        .transfer(receiver.account_id().clone(), amount, TxStatus::InBlock)
+        .await
+        .unwrap();
+
+    let balance = connection
+        .get_free_balance(receiver.account_id().clone(), None)
+        .await
+        .unwrap();
+    assert_eq!(balance, amount);
+}
@@ -0,0 +1,51 @@
+use futures::{StreamExt, TryStreamExt};
+use subxtxt::{
+    connection::{Connection, ConnectionApi},
+    Block, BlockNumber,
+};
+
+use crate::mock::{MockNode, MockStorage};
+
+mod mock;
+
+fn numbers(blocks: &[Block]) -> Vec<BlockNumber> {
+    blocks.iter().map(|block| block.number).collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn finalized_blocks_are_backfilled() {
+    let node = MockNode::start(MockStorage::default().with_heads(vec![1, 2, 5, 5, 6])).await;
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let blocks: Vec<Block> = connection
+        .subscribe_finalized_blocks()
+        .await
+        .unwrap()
+        .take(6)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(numbers(&blocks), vec![1, 2, 3, 4, 5, 6]);
+    for pair in blocks.windows(2) {
+        assert_eq!(pair[1].parent_hash, pair[0].hash);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn best_blocks_are_streamed_as_announced() {
+    let node = MockNode::start(MockStorage::default().with_heads(vec![1, 3])).await;
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let blocks: Vec<Block> = connection
+        .subscribe_best_blocks()
+        .await
+        .unwrap()
+        .take(2)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(numbers(&blocks), vec![1, 3]);
+    assert!(blocks.iter().all(|block| block.extrinsics.is_empty()));
+}
@@ -0,0 +1,48 @@
+use subxt::ext::sp_runtime::{DispatchError as RuntimeError, ModuleError};
+use subxtxt::{
+    connection::{SignedConnection, SignedConnectionApi},
+    keypair_from_string, DispatchError, Error, TxStatus,
+};
+
+use crate::mock::{MockNode, MockStorage, RawCall, TxFate, BALANCES_INDEX};
+
+mod mock;
+
+/// Sends a transaction whose dispatch fails with `error` and returns the reported failure.
+async fn dispatch_failure(error: RuntimeError) -> DispatchError {
+    let storage = MockStorage::default()
+        .with_heads(vec![1, 2])
+        .with_tx_fate(TxFate::Included(2))
+        .with_failed_txs(error);
+    let node = MockNode::start(storage).await;
+    let connection = SignedConnection::new(
+        &node.node_address(),
+        keypair_from_string("//Alice").unwrap(),
+    )
+    .await
+    .unwrap();
+
+    match connection.send_tx(RawCall, TxStatus::InBlock).await {
+        Err(Error::Dispatch(e)) => e,
+        result => panic!("Expected dispatch error, got {result:?}"),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pallet_errors_are_named() {
+    let error = dispatch_failure(RuntimeError::Module(ModuleError {
+        index: BALANCES_INDEX,
+        error: [0; 4],
+        message: None,
+    }))
+    .await;
+
+    assert!(error.is("Balances", "InsufficientBalance"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn other_errors_are_decoded() {
+    let error = dispatch_failure(RuntimeError::BadOrigin).await;
+
+    assert_eq!(error, DispatchError::Other("BadOrigin".to_string()));
+}
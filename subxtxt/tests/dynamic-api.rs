@@ -0,0 +1,40 @@
+use subxt::ext::scale_value::{Composite, ValueDef};
+use subxtxt::{
+    connection::{Connection, ConnectionApi},
+    keypair_from_string,
+};
+
+use crate::mock::{MockNode, MockStorage};
+
+mod mock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dynamic_storage_is_decoded_with_metadata() {
+    let sudo = keypair_from_string("//Alice").unwrap();
+    let node = MockNode::start(MockStorage::default().with_sudo(sudo.account_id().clone())).await;
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let key = connection
+        .get_dynamic_storage("Sudo", "Key", vec![], None)
+        .await
+        .unwrap()
+        .expect("Sudo key should be set");
+
+    assert!(matches!(
+        key.value,
+        ValueDef::Composite(Composite::Unnamed(bytes)) if bytes.len() == 32
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn missing_dynamic_storage_is_none() {
+    let node = MockNode::start(MockStorage::default()).await;
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let key = connection
+        .get_dynamic_storage("Sudo", "Key", vec![], None)
+        .await
+        .unwrap();
+
+    assert!(key.is_none());
+}
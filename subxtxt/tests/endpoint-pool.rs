@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use subxtxt::{
+    connection::{Connection, SignedConnection, SignedConnectionApi},
+    keypair_from_string,
+    rpc::LoadBalancing,
+    RetryPolicy, TxStatus,
+};
+
+use crate::mock::{MockNode, MockStorage, RawCall, TxFate};
+
+mod mock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pool_fails_over_to_live_node() {
+    let sudo = keypair_from_string("//Alice").unwrap();
+    let storage = MockStorage::default().with_sudo(sudo.account_id().clone());
+    let first = MockNode::start(storage.clone()).await;
+    let second = MockNode::start(storage).await;
+
+    let connection = Connection::with_endpoints(&[&first.node_address(), &second.node_address()])
+        .await
+        .unwrap();
+    let connection = SignedConnection::from_connection(connection, sudo);
+
+    first.stop().await;
+    for _ in 0..4 {
+        assert!(connection.try_as_root().await.is_ok());
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pool_skips_unreachable_node_on_start() {
+    let sudo = keypair_from_string("//Alice").unwrap();
+    let storage = MockStorage::default().with_sudo(sudo.account_id().clone());
+    let dead = MockNode::start(storage.clone()).await;
+    let alive = MockNode::start(storage).await;
+    dead.stop().await;
+
+    let connection =
+        Connection::builder_with_endpoints(&[&dead.node_address(), &alive.node_address()])
+            .retry_policy(RetryPolicy::never())
+            .load_balancing(LoadBalancing::LowestLatency)
+            .build()
+            .await
+            .unwrap();
+    let connection = SignedConnection::from_connection(connection, sudo);
+
+    assert!(connection.try_as_root().await.is_ok());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tx_is_watched_by_node_that_included_it() {
+    let primary = MockNode::start(
+        MockStorage::default()
+            .with_heads(vec![1, 2, 3])
+            .with_tx_fate(TxFate::Included(3)),
+    )
+    .await;
+    // Lagging behind, so it does not know the block including the transaction.
+    let lagging = MockNode::start(MockStorage::default().with_heads(vec![1, 2])).await;
+
+    let connection =
+        Connection::with_endpoints(&[&primary.node_address(), &lagging.node_address()])
+            .await
+            .unwrap();
+    let connection =
+        SignedConnection::from_connection(connection, keypair_from_string("//Alice").unwrap());
+
+    // Round robin would send every other follow-up request to the lagging node.
+    for _ in 0..4 {
+        let info = connection
+            .send_tx(RawCall, TxStatus::InBlock)
+            .await
+            .unwrap();
+        assert_eq!(
+            info.block_hash,
+            Some(MockStorage::default().header(3).hash())
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tx_submission_is_not_sent_to_another_node() {
+    let first_storage = MockStorage::default().with_tx_fate(TxFate::Unanswered);
+    let second_storage = MockStorage::default();
+    let first = MockNode::start(first_storage.clone()).await;
+    let second = MockNode::start(second_storage.clone()).await;
+
+    let connection = Connection::with_endpoints(&[&first.node_address(), &second.node_address()])
+        .await
+        .unwrap();
+    let connection =
+        SignedConnection::from_connection(connection, keypair_from_string("//Alice").unwrap());
+
+    let submission =
+        tokio::spawn(async move { connection.send_tx(RawCall, TxStatus::Submitted).await });
+    while first_storage.submitted().is_empty() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    first.stop().await;
+
+    assert!(submission.await.unwrap().is_err());
+    assert_eq!(first_storage.submitted().len(), 1);
+    assert!(second_storage.submitted().is_empty());
+}
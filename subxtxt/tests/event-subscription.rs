@@ -0,0 +1,121 @@
+use futures::{StreamExt, TryStreamExt};
+use parity_scale_codec::Decode;
+use subxt::events::{Phase, StaticEvent};
+use subxtxt::{
+    connection::{Connection, ConnectionApi},
+    BlockStatus, Error, ObservedEvent, WaitTimeout,
+};
+
+use crate::mock::{MockNode, MockStorage, StakingEvent};
+
+mod mock;
+
+#[derive(Debug, Eq, PartialEq, Decode)]
+struct Rewarded {
+    stash: [u8; 32],
+    amount: u128,
+}
+
+impl StaticEvent for Rewarded {
+    const PALLET: &'static str = "Staking";
+    const EVENT: &'static str = "Rewarded";
+}
+
+fn rewarded(stash: u8, amount: u128) -> StakingEvent {
+    StakingEvent::Rewarded {
+        stash: [stash; 32],
+        amount,
+    }
+}
+
+async fn start_node() -> MockNode {
+    let storage = MockStorage::default()
+        .with_heads(vec![1, 3])
+        .with_event(2, rewarded(1, 100))
+        .with_event(3, rewarded(2, 200))
+        .with_event(3, rewarded(3, 300));
+    MockNode::start(storage).await
+}
+
+async fn observe(status: BlockStatus, count: usize) -> Vec<ObservedEvent<Rewarded>> {
+    let node = start_node().await;
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    connection
+        .subscribe_events::<Rewarded>(status)
+        .await
+        .unwrap()
+        .take(count)
+        .try_collect()
+        .await
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn finalized_events_are_observed_in_order() {
+    let events = observe(BlockStatus::Finalized, 3).await;
+
+    let observed = events
+        .iter()
+        .map(|observed| (observed.block_number, observed.event.stash[0]))
+        .collect::<Vec<_>>();
+    assert_eq!(observed, vec![(2, 1), (3, 2), (3, 3)]);
+    assert!(events.iter().all(|observed| {
+        observed.phase == Phase::ApplyExtrinsic(0) && observed.extrinsic_index == Some(0)
+    }));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn best_events_come_from_announced_blocks_only() {
+    let events = observe(BlockStatus::Best, 2).await;
+
+    assert_eq!(
+        events
+            .into_iter()
+            .map(|observed| observed.event)
+            .collect::<Vec<_>>(),
+        vec![
+            Rewarded {
+                stash: [2; 32],
+                amount: 200
+            },
+            Rewarded {
+                stash: [3; 32],
+                amount: 300
+            },
+        ]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn waits_for_matching_event() {
+    let node = start_node().await;
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+
+    let observed = connection
+        .wait_for_event(
+            |event: &Rewarded| event.amount > 100,
+            BlockStatus::Finalized,
+            WaitTimeout::Blocks(3),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(observed.block_number, 3);
+    assert_eq!(observed.event.stash, [2; 32]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn waiting_for_event_times_out_after_given_blocks() {
+    let node = start_node().await;
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+
+    let result = connection
+        .wait_for_event(
+            |event: &Rewarded| event.amount > 100,
+            BlockStatus::Finalized,
+            WaitTimeout::Blocks(2),
+        )
+        .await;
+
+    assert!(matches!(result, Err(Error::Timeout(_))));
+}
@@ -0,0 +1,51 @@
+use subxtxt::{
+    connection::SignedConnectionApi, DispatchClass, DispatchError, DryRunOutcome, Error,
+    InclusionFee,
+};
+
+use crate::mock::{MockNode, MockStorage, RawCall, MOCK_INCLUSION_FEE, MOCK_PARTIAL_FEE};
+
+mod mock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fee_is_estimated_with_breakdown() {
+    let node = MockNode::start(MockStorage::default()).await;
+    let connection = node.signed_connection().await;
+
+    let estimate = connection.estimate_fee(RawCall).await.unwrap();
+
+    let (base_fee, len_fee, adjusted_weight_fee) = MOCK_INCLUSION_FEE;
+    assert_eq!(estimate.class, DispatchClass::Normal);
+    assert_eq!(estimate.partial_fee, MOCK_PARTIAL_FEE);
+    assert_eq!(
+        estimate.inclusion_fee,
+        Some(InclusionFee {
+            base_fee,
+            len_fee,
+            adjusted_weight_fee,
+        })
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn malformed_fee_details_are_rejected() {
+    let node = MockNode::start(MockStorage::default().with_fee_details_suffix(vec![0; 16])).await;
+    let connection = node.signed_connection().await;
+
+    let result = connection.estimate_fee(RawCall).await;
+
+    assert!(matches!(result, Err(Error::Decode(_))));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dry_run_reports_failed_dispatch() {
+    let node = MockNode::start(MockStorage::default()).await;
+    let connection = node.signed_connection().await;
+
+    let outcome = connection.dry_run(RawCall).await.unwrap();
+
+    assert_eq!(
+        outcome,
+        DryRunOutcome::DispatchFailed(DispatchError::Other("BadOrigin".to_string()))
+    );
+}
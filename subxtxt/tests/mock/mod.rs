@@ -0,0 +1,989 @@
+// Not every test binary uses every helper.
+#![allow(dead_code)]
+
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use frame_metadata::{
+    ExtrinsicMetadata, PalletCallMetadata, PalletErrorMetadata, PalletEventMetadata,
+    PalletMetadata, PalletStorageMetadata, RuntimeMetadataPrefixed, RuntimeMetadataV14,
+    StorageEntryMetadata, StorageEntryModifier, StorageEntryType, StorageHasher,
+};
+use jsonrpsee::{
+    core::Error as JsonRpcError,
+    server::{ServerBuilder, ServerHandle},
+    types::ErrorObject,
+    RpcModule,
+};
+use parity_scale_codec::Encode;
+use scale_info::{meta_type, TypeInfo};
+use serde_json::json;
+use subxt::{
+    events::Phase,
+    ext::{
+        sp_core::{blake2_128, twox_128, twox_64, Bytes},
+        sp_runtime::{
+            generic::{Digest, Header as GenericHeader},
+            traits::{BlakeTwo256, Header as _},
+            ApplyExtrinsicResult, DispatchError, MultiAddress, MultiSignature,
+        },
+    },
+    tx::TxPayload,
+    Metadata, PolkadotConfig,
+};
+use subxtxt::{
+    connection::{Connection, ConnectionBuilder, SignedConnection},
+    keypair_from_string, AccountId, BlockHash, BlockNumber, Error, KeyPair, Signer, Weight,
+};
+
+mod signer;
+
+pub use signer::*;
+
+/// Header of the blocks produced by the mocked node.
+pub type Header = GenericHeader<BlockNumber, BlakeTwo256>;
+
+/// Partial fee of every transaction, as estimated by the mocked node.
+pub const MOCK_PARTIAL_FEE: u128 = 150;
+/// Breakdown of [`MOCK_PARTIAL_FEE`] into base, length and adjusted weight fee.
+pub const MOCK_INCLUSION_FEE: (u128, u128, u128) = (100, 20, 30);
+
+/// How long the mocked node keeps a transaction in the pool before including it in a block.
+pub const INCLUSION_DELAY: Duration = Duration::from_millis(200);
+/// Encoded extrinsic that precedes the submitted transactions in the block including them.
+const INHERENT: [u8; 2] = [4, 0];
+
+/// Base URL of the mocked node.
+pub const BASE_URL: &str = "ws://127.0.0.1";
+
+/// In-process stand-in for a chain node, serving just enough RPC methods for `OnlineClient` to
+/// connect and to read raw storage.
+pub struct MockNode {
+    /// Handle of the running RPC server. Dropping it stops the server.
+    handle: ServerHandle,
+    /// Address that the server listens on.
+    local_address: SocketAddr,
+    /// Storage served by the node, kept to survive restarts.
+    storage: MockStorage,
+}
+
+/// Raw storage served by a [`MockNode`].
+#[derive(Clone, Default)]
+pub struct MockStorage {
+    entries: HashMap<Vec<u8>, Vec<u8>>,
+    nonces: HashMap<AccountId, u32>,
+    heads: Vec<BlockNumber>,
+    events: HashMap<BlockNumber, Vec<StakingEvent>>,
+    finalized: Option<BlockNumber>,
+    tx_fate: TxFate,
+    tx_events: Vec<StakingEvent>,
+    tx_error: Option<DispatchError>,
+    /// Bytes appended to the encoded fee details, which makes them malformed.
+    fee_details_suffix: Vec<u8>,
+    /// Changes announced to storage subscriptions: block number, raw key and new raw value.
+    storage_changes: Vec<(BlockNumber, Vec<u8>, Option<Vec<u8>>)>,
+    /// Number of storage subscriptions opened so far, shared by all clones.
+    storage_subscriptions: Arc<AtomicU32>,
+    /// Number of submissions still to be rejected.
+    rejections: Arc<AtomicU32>,
+    /// Transactions accepted so far, shared by all clones.
+    submitted: Arc<Mutex<Vec<Bytes>>>,
+}
+
+/// What happens to the transactions submitted to a [`MockNode`].
+#[derive(Clone, Copy, Debug, Default)]
+pub enum TxFate {
+    /// They stay in the pool forever: the node reports them as ready and never answers again.
+    #[default]
+    Pending,
+    /// They are dropped from the pool right after being accepted.
+    Dropped,
+    /// They are accepted into the pool, but the node never answers the submission.
+    Unanswered,
+    /// They are accepted into the pool, but the node stops reporting their status right away.
+    Unwatched,
+    /// After [`INCLUSION_DELAY`], they are included in the block with the given number, which is
+    /// never finalized.
+    InBlock(BlockNumber),
+    /// After [`INCLUSION_DELAY`], they are included in the block with the given number, which is
+    /// finalized right away.
+    Included(BlockNumber),
+    /// After [`INCLUSION_DELAY`], they are included in the block with the given number, which is
+    /// retracted right away.
+    Retracted(BlockNumber),
+}
+
+/// Events of pallet system emitted by the mocked node.
+#[derive(Clone, Encode, TypeInfo)]
+pub enum SystemEvent {
+    /// Mirrors `System::ExtrinsicFailed`, without the dispatch info.
+    ExtrinsicFailed {
+        /// Reason of the failure.
+        dispatch_error: DispatchError,
+    },
+}
+
+/// Errors of pallet balances known to the mocked node.
+#[derive(Clone, Encode, TypeInfo)]
+pub enum BalancesError {
+    /// Balance too low to send value.
+    InsufficientBalance,
+}
+
+/// Calls of pallet balances known to the mocked node, named like in recent Substrate runtimes.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Encode, TypeInfo)]
+pub enum BalancesCall {
+    /// Transfers `value` tokens to `dest`.
+    transfer_allow_death {
+        /// Receiver of the tokens.
+        dest: MultiAddress<[u8; 32], ()>,
+        /// Amount of tokens.
+        #[codec(compact)]
+        value: u128,
+    },
+}
+
+/// Calls of the runtime of the mocked node that can be nested in other calls.
+#[derive(Clone, Encode, TypeInfo)]
+pub enum RuntimeCall {
+    /// Calls of pallet sudo.
+    #[codec(index = 0)]
+    Sudo(SudoCall),
+    /// Calls of pallet staking.
+    #[codec(index = 1)]
+    Staking(StakingCall),
+    /// Calls of pallet balances.
+    #[codec(index = 3)]
+    Balances(BalancesCall),
+    /// Calls of pallet utility.
+    #[codec(index = 4)]
+    Utility(UtilityCall),
+}
+
+/// Calls of pallet sudo known to the mocked node.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Encode, TypeInfo)]
+pub enum SudoCall {
+    /// Dispatches `call` as root.
+    sudo {
+        /// Dispatched call.
+        call: Box<RuntimeCall>,
+    },
+    /// Dispatches `call` as `who`.
+    sudo_as {
+        /// Origin of the dispatched call.
+        who: MultiAddress<[u8; 32], ()>,
+        /// Dispatched call.
+        call: Box<RuntimeCall>,
+    },
+}
+
+/// Calls of pallet staking known to the mocked node, shaped like in recent Substrate runtimes,
+/// where every stash is its own controller.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Encode, TypeInfo)]
+pub enum StakingCall {
+    /// Bonds `value` tokens of the stash.
+    bond {
+        /// Amount of tokens.
+        #[codec(compact)]
+        value: u128,
+        /// Where the rewards go.
+        payee: RewardDestination,
+    },
+    /// Sets the staking config.
+    set_staking_configs {
+        /// Minimal bond of a nominator.
+        min_nominator_bond: ConfigOp<u128>,
+        /// Minimal bond of a validator.
+        min_validator_bond: ConfigOp<u128>,
+        /// Maximal number of nominators.
+        max_nominator_count: ConfigOp<u32>,
+        /// Maximal number of validators.
+        max_validator_count: ConfigOp<u32>,
+        /// Threshold of chilling other stakers, a `Percent`.
+        chill_threshold: ConfigOp<u8>,
+        /// Minimal commission of a validator, a `Perbill`.
+        min_commission: ConfigOp<u32>,
+        /// Maximal part of the era payout going to stakers, a `Percent`.
+        max_staked_rewards: ConfigOp<u8>,
+    },
+}
+
+/// Mirrors `pallet_staking::RewardDestination`, up to the variant used by the tests.
+#[derive(Clone, Encode, TypeInfo)]
+pub enum RewardDestination {
+    /// Rewards are added to the bonded stake.
+    Staked,
+}
+
+/// Mirrors `pallet_staking::ConfigOp`.
+#[derive(Clone, Encode, TypeInfo)]
+pub enum ConfigOp<T> {
+    /// The option is left unchanged.
+    Noop,
+    /// The option is set to the given value.
+    Set(T),
+    /// The option is removed.
+    Remove,
+}
+
+/// Calls of pallet utility known to the mocked node.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Encode, TypeInfo)]
+pub enum UtilityCall {
+    /// Dispatches `calls` one after another.
+    batch {
+        /// Dispatched calls.
+        calls: Vec<RuntimeCall>,
+    },
+}
+
+/// Raw key of `Staking::Bonded` of `stash`.
+fn bonded_key(stash: &AccountId) -> Vec<u8> {
+    let stash = stash.encode();
+    [
+        twox_128(b"Staking").as_slice(),
+        &twox_128(b"Bonded"),
+        &twox_64(&stash),
+        &stash,
+    ]
+    .concat()
+}
+
+/// Mirrors `pallet_balances::BalanceLock`, without the reasons.
+#[derive(Clone, Encode, TypeInfo)]
+pub struct BalanceLock {
+    /// Identifier of the lock.
+    pub id: [u8; 8],
+    /// Amount of locked tokens.
+    pub amount: u128,
+}
+
+/// Index of pallet balances in the metadata of the mocked node.
+pub const BALANCES_INDEX: u8 = 3;
+
+/// Event of any pallet, encoded with the index of the pallet.
+#[derive(Encode)]
+enum RuntimeEvent<'a> {
+    #[codec(index = 1)]
+    Staking(&'a StakingEvent),
+    #[codec(index = 2)]
+    System(SystemEvent),
+}
+
+/// Events of pallet staking emitted by the mocked node.
+#[derive(Clone, Encode, TypeInfo)]
+pub enum StakingEvent {
+    /// Mirrors `Staking::Rewarded`.
+    Rewarded {
+        /// Rewarded stash.
+        stash: [u8; 32],
+        /// Reward.
+        amount: u128,
+    },
+}
+
+impl MockStorage {
+    /// Sets `Sudo::Key` to `sudo`.
+    pub fn with_sudo(mut self, sudo: AccountId) -> Self {
+        let key = [twox_128(b"Sudo"), twox_128(b"Key")].concat();
+        self.entries.insert(key, sudo.encode());
+        self
+    }
+
+    /// Sets `Staking::Bonded` of `stash` to `controller`.
+    pub fn with_bonded(mut self, stash: AccountId, controller: AccountId) -> Self {
+        self.entries.insert(bonded_key(&stash), controller.encode());
+        self
+    }
+
+    /// Announces to storage subscriptions that `Staking::Bonded` of `stash` changed to
+    /// `controller` (or was removed, if `None`) in the block with `number`, after
+    /// [`INCLUSION_DELAY`]. The value served by other requests does not change.
+    pub fn with_bonded_change(
+        mut self,
+        number: BlockNumber,
+        stash: AccountId,
+        controller: Option<AccountId>,
+    ) -> Self {
+        let controller = controller.map(|controller| controller.encode());
+        self.storage_changes
+            .push((number, bonded_key(&stash), controller));
+        self
+    }
+
+    /// Makes the node append `suffix` to the fee details it returns.
+    pub fn with_fee_details_suffix(mut self, suffix: Vec<u8>) -> Self {
+        self.fee_details_suffix = suffix;
+        self
+    }
+
+    /// Returns the number of storage subscriptions opened so far.
+    pub fn storage_subscriptions(&self) -> u32 {
+        self.storage_subscriptions.load(Ordering::SeqCst)
+    }
+
+    /// Sets `Balances::Locks` of `account` to `locks`.
+    pub fn with_locks(mut self, account: AccountId, locks: Vec<BalanceLock>) -> Self {
+        let account = account.encode();
+        let key = [
+            twox_128(b"Balances").as_slice(),
+            &twox_128(b"Locks"),
+            &blake2_128(&account),
+            &account,
+        ]
+        .concat();
+        self.entries.insert(key, locks.encode());
+        self
+    }
+
+    /// Sets the numbers of blocks announced by the best and finalized head subscriptions, in order.
+    /// The chain consists of empty blocks, up to the highest of the heads.
+    pub fn with_heads(mut self, heads: Vec<BlockNumber>) -> Self {
+        self.heads = heads;
+        self
+    }
+
+    /// Sets the finalized head to the block with `number`. By default, it is the best block.
+    pub fn with_finalized(mut self, number: BlockNumber) -> Self {
+        self.finalized = Some(number);
+        self
+    }
+
+    /// Makes the node drop every submitted transaction from the pool right after accepting it.
+    pub fn with_dropped_txs(self) -> Self {
+        self.with_tx_fate(TxFate::Dropped)
+    }
+
+    /// Sets what happens to submitted transactions. By default, they stay in the pool forever.
+    pub fn with_tx_fate(mut self, fate: TxFate) -> Self {
+        self.tx_fate = fate;
+        self
+    }
+
+    /// Emits `event` from every included transaction.
+    pub fn with_tx_event(mut self, event: StakingEvent) -> Self {
+        self.tx_events.push(event);
+        self
+    }
+
+    /// Makes the dispatch of every included transaction fail with `error`.
+    pub fn with_failed_txs(mut self, error: DispatchError) -> Self {
+        self.tx_error = Some(error);
+        self
+    }
+
+    /// Makes the node reject the next `count` submitted transactions, before they enter the pool.
+    pub fn with_rejected_txs(self, count: u32) -> Self {
+        self.rejections.store(count, Ordering::SeqCst);
+        self
+    }
+
+    /// Returns the transactions accepted by the node so far.
+    pub fn submitted(&self) -> Vec<Bytes> {
+        self.submitted.lock().unwrap().clone()
+    }
+
+    /// Emits `event` in the block with `number`, as the only event of the first extrinsic.
+    pub fn with_event(mut self, number: BlockNumber, event: StakingEvent) -> Self {
+        self.events.entry(number).or_default().push(event);
+        self
+    }
+
+    /// Returns the header of the block with `number`.
+    pub fn header(&self, number: BlockNumber) -> Header {
+        let parent_hash = match number {
+            0 => BlockHash::zero(),
+            _ => self.header(number - 1).hash(),
+        };
+        Header::new(
+            number,
+            Default::default(),
+            Default::default(),
+            parent_hash,
+            Digest::default(),
+        )
+    }
+
+    /// Returns the header of the block with `hash`, if it is a part of the chain.
+    fn header_by_hash(&self, hash: BlockHash) -> Option<Header> {
+        let best = self.heads.iter().max().copied().unwrap_or_default();
+        (0..=best)
+            .map(|number| self.header(number))
+            .find(|header| header.hash() == hash)
+    }
+
+    /// Returns the number of the block including submitted transactions, if there is any.
+    fn inclusion_block(&self) -> Option<BlockNumber> {
+        match self.tx_fate {
+            TxFate::InBlock(number) | TxFate::Included(number) | TxFate::Retracted(number) => {
+                Some(number)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the encoded extrinsics of the block with `number`.
+    fn extrinsics(&self, number: BlockNumber) -> Vec<Bytes> {
+        match self.inclusion_block() == Some(number) {
+            true => [vec![Bytes(INHERENT.to_vec())], self.submitted()].concat(),
+            false => vec![],
+        }
+    }
+
+    /// Returns encoded `System::Events` of the block with `hash`.
+    fn events_at(&self, hash: BlockHash) -> Option<Vec<u8>> {
+        let number = self.header_by_hash(hash)?.number;
+        let mut records = self
+            .events
+            .get(&number)
+            .into_iter()
+            .flatten()
+            .map(|event| (Phase::ApplyExtrinsic(0), RuntimeEvent::Staking(event)))
+            .collect::<Vec<_>>();
+        if self.inclusion_block() == Some(number) {
+            // The first extrinsic is the inherent.
+            for index in 1..=self.submitted().len() as u32 {
+                let phase = Phase::ApplyExtrinsic(index);
+                records.extend(
+                    self.tx_events
+                        .iter()
+                        .map(|event| (phase.clone(), RuntimeEvent::Staking(event))),
+                );
+                if let Some(dispatch_error) = self.tx_error {
+                    let event = SystemEvent::ExtrinsicFailed { dispatch_error };
+                    records.push((phase, RuntimeEvent::System(event)));
+                }
+            }
+        }
+        if records.is_empty() {
+            return None;
+        }
+
+        // Phase, event and topics.
+        let records = records
+            .into_iter()
+            .map(|(phase, event)| (phase, event, Vec::<BlockHash>::new()))
+            .collect::<Vec<_>>();
+        Some(records.encode())
+    }
+
+    /// Sets the next nonce of `account`, as reported by `system_accountNextIndex`.
+    pub fn with_nonce(mut self, account: AccountId, nonce: u32) -> Self {
+        self.nonces.insert(account, nonce);
+        self
+    }
+}
+
+impl MockNode {
+    /// Starts a mocked node on a random local port, serving `storage`.
+    pub async fn start(storage: MockStorage) -> Self {
+        Self::start_at("127.0.0.1:0".parse().unwrap(), storage).await
+    }
+
+    async fn start_at(address: SocketAddr, storage: MockStorage) -> Self {
+        let server = ServerBuilder::default()
+            .build(address)
+            .await
+            .expect("Should bind mocked node to a local port");
+        let local_address = server.local_addr().expect("Should have local address");
+
+        let handle = server
+            .start(rpc_module(storage.clone()))
+            .expect("Should start mocked node");
+
+        Self {
+            handle,
+            local_address,
+            storage,
+        }
+    }
+
+    /// Stops the node, which drops all open connections.
+    pub async fn stop(&self) {
+        self.handle.stop().expect("Should stop mocked node");
+        self.handle.clone().stopped().await;
+    }
+
+    /// Stops the node and starts it again on the same port.
+    pub async fn restart(&mut self) {
+        self.stop().await;
+        *self = Self::start_at(self.local_address, self.storage.clone()).await;
+    }
+
+    /// Stops the node and starts it again on the same port, serving `storage` from then on.
+    pub async fn restart_with(&mut self, storage: MockStorage) {
+        self.storage = storage;
+        self.restart().await;
+    }
+
+    /// Returns the web socket address of the node.
+    pub fn node_address(&self) -> String {
+        format!("{}:{}", BASE_URL, self.local_address.port())
+    }
+
+    /// Connects to the node, signing as Alice.
+    pub async fn signed_connection(&self) -> SignedConnection {
+        self.signed_connection_with(|builder| builder).await
+    }
+
+    /// Connects to the node with a connection set up by `configure`, signing as Alice.
+    pub async fn signed_connection_with(
+        &self,
+        configure: impl FnOnce(ConnectionBuilder) -> ConnectionBuilder,
+    ) -> SignedConnection {
+        let connection = configure(Connection::builder(&self.node_address()))
+            .build()
+            .await
+            .expect("Should connect to mocked node");
+        SignedConnection::from_connection(connection, keypair_from_string("//Alice").unwrap())
+    }
+}
+
+/// A call that is not validated against the runtime metadata.
+pub struct RawCall;
+
+impl TxPayload for RawCall {
+    fn encode_call_data(&self, _: &Metadata, out: &mut Vec<u8>) -> Result<(), subxt::Error> {
+        out.extend([0, 1]);
+        Ok(())
+    }
+}
+
+/// Delegates to a key pair, counting the signed payloads. Fails to sign the first `failures`
+/// payloads.
+pub struct DelegatingSigner {
+    key_pair: KeyPair,
+    signed: Arc<AtomicU32>,
+    failures: AtomicU32,
+}
+
+impl DelegatingSigner {
+    /// Creates a signer delegating to `key_pair`.
+    pub fn new(key_pair: KeyPair) -> Self {
+        Self {
+            key_pair,
+            signed: Arc::new(AtomicU32::new(0)),
+            failures: AtomicU32::new(0),
+        }
+    }
+
+    /// Makes the signer fail to sign the first `failures` payloads.
+    pub fn with_failures(self, failures: u32) -> Self {
+        Self {
+            failures: AtomicU32::new(failures),
+            ..self
+        }
+    }
+
+    /// Returns the counter of signed payloads, which stays valid after the signer is moved.
+    pub fn signed(&self) -> Arc<AtomicU32> {
+        self.signed.clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl Signer for DelegatingSigner {
+    fn account_id(&self) -> &AccountId {
+        self.key_pair.account_id()
+    }
+
+    fn address(&self) -> MultiAddress<AccountId, u32> {
+        self.key_pair.account_id().clone().into()
+    }
+
+    async fn sign(&self, payload: &[u8]) -> subxtxt::Result<MultiSignature> {
+        let failures = self.failures.load(Ordering::SeqCst);
+        if failures > 0 {
+            self.failures.store(failures - 1, Ordering::SeqCst);
+            return Err(Error::Signer("signer unavailable".to_string()));
+        }
+        self.signed.fetch_add(1, Ordering::SeqCst);
+        Signer::<PolkadotConfig>::sign(&self.key_pair, payload).await
+    }
+}
+
+fn rpc_module(storage: MockStorage) -> RpcModule<MockStorage> {
+    let mut module = RpcModule::new(storage);
+
+    module
+        .register_method("chain_getBlockHash", |params, storage| {
+            let number: Option<BlockNumber> = params.sequence().optional_next()?;
+            Ok(match number {
+                Some(number) if !storage.heads.is_empty() => storage.header(number).hash(),
+                _ => BlockHash::zero(),
+            })
+        })
+        .unwrap();
+    module
+        .register_method("chain_getHeader", |params, storage| {
+            let hash: Option<BlockHash> = params.sequence().optional_next()?;
+            Ok(match hash {
+                Some(hash) => storage.header_by_hash(hash),
+                None => storage.heads.iter().max().map(|best| storage.header(*best)),
+            })
+        })
+        .unwrap();
+    module
+        .register_method("chain_getFinalizedHead", |_, storage| {
+            let finalized = storage
+                .finalized
+                .or_else(|| storage.heads.iter().max().copied())
+                .unwrap_or_default();
+            Ok(storage.header(finalized).hash())
+        })
+        .unwrap();
+    module
+        .register_method("chain_getBlock", |params, storage| {
+            let hash: BlockHash = params.sequence().next()?;
+            Ok(storage.header_by_hash(hash).map(|header| {
+                let extrinsics = storage.extrinsics(header.number);
+                json!({ "block": { "header": header, "extrinsics": extrinsics }, "justifications": null })
+            }))
+        })
+        .unwrap();
+    for (subscribe, notification, unsubscribe) in [
+        (
+            "chain_subscribeNewHeads",
+            "chain_newHead",
+            "chain_unsubscribeNewHeads",
+        ),
+        (
+            "chain_subscribeFinalizedHeads",
+            "chain_finalizedHead",
+            "chain_unsubscribeFinalizedHeads",
+        ),
+    ] {
+        module
+            .register_subscription(
+                subscribe,
+                notification,
+                unsubscribe,
+                |_, mut sink, storage| {
+                    for head in &storage.heads {
+                        let _ = sink.send(&storage.header(*head));
+                    }
+                    Ok(())
+                },
+            )
+            .unwrap();
+    }
+    module
+        .register_method("state_getRuntimeVersion", |_, _| {
+            Ok(json!({ "specVersion": 1, "transactionVersion": 1 }))
+        })
+        .unwrap();
+    module
+        .register_method("state_getMetadata", |_, _| Ok(metadata()))
+        .unwrap();
+    module
+        .register_method("state_getStorage", |params, storage| {
+            let mut params = params.sequence();
+            let key: Bytes = params.next()?;
+            let at: Option<BlockHash> = params.optional_next()?;
+            if key.0 == [twox_128(b"System"), twox_128(b"Events")].concat() {
+                return Ok(at.and_then(|at| storage.events_at(at)).map(Bytes));
+            }
+            Ok(storage.entries.get(&key.0).cloned().map(Bytes))
+        })
+        .unwrap();
+    module
+        .register_method("state_getKeysPaged", |params, storage| {
+            let mut params = params.sequence();
+            let prefix: Bytes = params.next()?;
+            let count: usize = params.next()?;
+            let start_key: Option<Bytes> = params.optional_next()?;
+
+            let mut keys = storage
+                .entries
+                .keys()
+                .filter(|key| key.starts_with(&prefix.0))
+                .filter(|key| start_key.as_ref().map_or(true, |start| key > &&start.0))
+                .cloned()
+                .collect::<Vec<_>>();
+            keys.sort();
+            keys.truncate(count);
+            Ok(keys.into_iter().map(Bytes).collect::<Vec<_>>())
+        })
+        .unwrap();
+    module
+        .register_method("state_queryStorageAt", |params, storage| {
+            let keys: Vec<Bytes> = params.sequence().next()?;
+            let changes = keys
+                .into_iter()
+                .map(|key| {
+                    let value = storage.entries.get(&key.0).cloned().map(Bytes);
+                    (key, value)
+                })
+                .collect::<Vec<_>>();
+            Ok(json!([{ "block": BlockHash::zero(), "changes": changes }]))
+        })
+        .unwrap();
+    module
+        .register_subscription(
+            "state_subscribeStorage",
+            "state_storage",
+            "state_unsubscribeStorage",
+            |params, mut sink, storage| {
+                // The current values, followed by the announced changes.
+                storage.storage_subscriptions.fetch_add(1, Ordering::SeqCst);
+                let keys: Vec<Bytes> = params.one()?;
+                let changes = keys
+                    .iter()
+                    .map(|key| {
+                        let value = storage.entries.get(&key.0).cloned().map(Bytes);
+                        (key.clone(), value)
+                    })
+                    .collect::<Vec<_>>();
+                let _ = sink.send(&json!({ "block": BlockHash::zero(), "changes": changes }));
+
+                let later = storage
+                    .storage_changes
+                    .iter()
+                    .filter(|(_, key, _)| keys.iter().any(|requested| requested.0 == *key))
+                    .map(|(number, key, value)| {
+                        json!({
+                            "block": storage.header(*number).hash(),
+                            "changes": [(Bytes(key.clone()), value.clone().map(Bytes))],
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                tokio::spawn(async move {
+                    tokio::time::sleep(INCLUSION_DELAY).await;
+                    for change_set in later {
+                        let _ = sink.send(&change_set);
+                    }
+                    // Keeps the subscription open.
+                    std::future::pending::<()>().await;
+                });
+                Ok(())
+            },
+        )
+        .unwrap();
+    module
+        .register_method("state_call", |params, storage| {
+            let method: String = params.sequence().next()?;
+            let result = match method.as_str() {
+                // Weight, `DispatchClass::Normal` and partial fee.
+                "TransactionPaymentApi_query_info" => (
+                    Weight {
+                        ref_time: 1_000_000,
+                        proof_size: 1_000,
+                    },
+                    0u8,
+                    MOCK_PARTIAL_FEE,
+                )
+                    .encode(),
+                // Base, length and adjusted weight fee. The tip is not encoded.
+                "TransactionPaymentApi_query_fee_details" => [
+                    Some(MOCK_INCLUSION_FEE).encode(),
+                    storage.fee_details_suffix.clone(),
+                ]
+                .concat(),
+                _ => return Err(JsonRpcError::Custom(format!("unknown method {method}"))),
+            };
+            Ok(Bytes(result))
+        })
+        .unwrap();
+    module
+        .register_method("system_dryRun", |_, _| {
+            let result: ApplyExtrinsicResult = Ok(Err(DispatchError::BadOrigin));
+            Ok(Bytes(result.encode()))
+        })
+        .unwrap();
+    module
+        .register_subscription(
+            "author_submitAndWatchExtrinsic",
+            "author_extrinsicUpdate",
+            "author_unwatchExtrinsic",
+            |params, mut sink, storage| {
+                // Accepts any extrinsic, unless told to reject it.
+                let extrinsic: Bytes = params.one()?;
+                let rejected = storage
+                    .rejections
+                    .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+                        left.checked_sub(1)
+                    })
+                    .is_ok();
+                if rejected {
+                    let _ =
+                        sink.reject(ErrorObject::owned(1010, "Invalid Transaction", None::<()>));
+                    return Ok(());
+                }
+                storage.submitted.lock().unwrap().push(extrinsic);
+                if let TxFate::Unanswered = storage.tx_fate {
+                    // Neither accepts nor rejects the subscription.
+                    tokio::spawn(async move {
+                        let _sink = sink;
+                        std::future::pending::<()>().await;
+                    });
+                    return Ok(());
+                }
+
+                let _ = sink.send(&"ready");
+                let (number, last) = match storage.tx_fate {
+                    TxFate::Pending => {
+                        // Never answers again, but keeps the subscription open.
+                        tokio::spawn(async move {
+                            let _sink = sink;
+                            std::future::pending::<()>().await;
+                        });
+                        return Ok(());
+                    }
+                    TxFate::Dropped => {
+                        let _ = sink.send(&"dropped");
+                        return Ok(());
+                    }
+                    TxFate::Unwatched => return Ok(()),
+                    TxFate::Unanswered => unreachable!("Handled before accepting the subscription"),
+                    TxFate::InBlock(number) => (number, None),
+                    TxFate::Included(number) => (number, Some("finalized")),
+                    TxFate::Retracted(number) => (number, Some("retracted")),
+                };
+                let hash = storage.header(number).hash();
+                tokio::spawn(async move {
+                    tokio::time::sleep(INCLUSION_DELAY).await;
+                    let _ = sink.send(&json!({ "inBlock": hash }));
+                    if let Some(last) = last {
+                        let _ = sink.send(&json!({ (last): hash }));
+                    }
+                    // Keeps the subscription open.
+                    std::future::pending::<()>().await;
+                });
+                Ok(())
+            },
+        )
+        .unwrap();
+    module
+        .register_method("system_accountNextIndex", |params, storage| {
+            let account: AccountId = params.sequence().next()?;
+            Ok(storage.nonces.get(&account).copied().unwrap_or_default())
+        })
+        .unwrap();
+
+    module
+}
+
+/// Minimal runtime metadata: just the storage entries, calls, events and errors that the mocked
+/// node serves.
+fn metadata() -> Bytes {
+    let sudo = PalletMetadata {
+        name: "Sudo",
+        storage: Some(PalletStorageMetadata {
+            prefix: "Sudo",
+            entries: vec![StorageEntryMetadata {
+                name: "Key",
+                modifier: StorageEntryModifier::Optional,
+                ty: StorageEntryType::Plain(meta_type::<[u8; 32]>()),
+                default: vec![0],
+                docs: vec![],
+            }],
+        }),
+        calls: Some(PalletCallMetadata {
+            ty: meta_type::<SudoCall>(),
+        }),
+        event: None,
+        constants: vec![],
+        error: None,
+        index: 0,
+    };
+
+    let staking = PalletMetadata {
+        name: "Staking",
+        storage: Some(PalletStorageMetadata {
+            prefix: "Staking",
+            entries: vec![StorageEntryMetadata {
+                name: "Bonded",
+                modifier: StorageEntryModifier::Optional,
+                ty: StorageEntryType::Map {
+                    hashers: vec![StorageHasher::Twox64Concat],
+                    key: meta_type::<[u8; 32]>(),
+                    value: meta_type::<[u8; 32]>(),
+                },
+                default: vec![0],
+                docs: vec![],
+            }],
+        }),
+        calls: Some(PalletCallMetadata {
+            ty: meta_type::<StakingCall>(),
+        }),
+        event: Some(PalletEventMetadata {
+            ty: meta_type::<StakingEvent>(),
+        }),
+        constants: vec![],
+        error: None,
+        index: 1,
+    };
+
+    let system = PalletMetadata {
+        name: "System",
+        storage: None,
+        calls: None,
+        event: Some(PalletEventMetadata {
+            ty: meta_type::<SystemEvent>(),
+        }),
+        constants: vec![],
+        error: None,
+        index: 2,
+    };
+
+    let balances = PalletMetadata {
+        name: "Balances",
+        storage: Some(PalletStorageMetadata {
+            prefix: "Balances",
+            entries: vec![StorageEntryMetadata {
+                name: "Locks",
+                modifier: StorageEntryModifier::Default,
+                ty: StorageEntryType::Map {
+                    hashers: vec![StorageHasher::Blake2_128Concat],
+                    key: meta_type::<[u8; 32]>(),
+                    value: meta_type::<Vec<BalanceLock>>(),
+                },
+                default: vec![0],
+                docs: vec![],
+            }],
+        }),
+        calls: Some(PalletCallMetadata {
+            ty: meta_type::<BalancesCall>(),
+        }),
+        event: None,
+        constants: vec![],
+        error: Some(PalletErrorMetadata {
+            ty: meta_type::<BalancesError>(),
+        }),
+        index: BALANCES_INDEX,
+    };
+
+    let utility = PalletMetadata {
+        name: "Utility",
+        storage: None,
+        calls: Some(PalletCallMetadata {
+            ty: meta_type::<UtilityCall>(),
+        }),
+        event: None,
+        constants: vec![],
+        error: None,
+        index: 4,
+    };
+
+    let metadata = RuntimeMetadataV14::new(
+        vec![sudo, staking, system, balances, utility],
+        ExtrinsicMetadata {
+            ty: meta_type::<()>(),
+            version: 4,
+            signed_extensions: vec![],
+        },
+        meta_type::<()>(),
+    );
+
+    Bytes(RuntimeMetadataPrefixed::from(metadata).encode())
+}
@@ -0,0 +1,103 @@
+use std::{net::SocketAddr, path::Path};
+
+use jsonrpsee::{
+    server::{ServerBuilder, ServerHandle},
+    RpcModule,
+};
+use parity_scale_codec::Encode;
+use serde::Deserialize;
+use serde_json::{json, Value as JsonValue};
+use subxt::ext::{
+    sp_core::{Bytes, Pair},
+    sp_runtime::MultiSignature,
+};
+use subxtxt::{AccountId, RawKeyPair, SIGN_PAYLOAD_METHOD};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::UnixListener,
+};
+
+/// In-process stand-in for a signing daemon. It signs every payload with its own key, regardless
+/// of the account it is asked to sign for.
+pub struct MockSigner {
+    /// Handle of the running HTTP server. Dropping it stops the server.
+    _handle: ServerHandle,
+    /// Address that the server listens on.
+    local_address: SocketAddr,
+}
+
+/// Params of [`SIGN_PAYLOAD_METHOD`].
+#[derive(Deserialize)]
+struct SignParams {
+    account_id: AccountId,
+    payload: Bytes,
+}
+
+impl MockSigner {
+    /// Starts a signer serving HTTP on a random local port.
+    pub async fn start_http(key: RawKeyPair) -> Self {
+        let server = ServerBuilder::default()
+            .build("127.0.0.1:0".parse::<SocketAddr>().unwrap())
+            .await
+            .expect("Should bind mocked signer to a local port");
+        let local_address = server.local_addr().expect("Should have local address");
+
+        let mut module = RpcModule::new(key);
+        module
+            .register_method(SIGN_PAYLOAD_METHOD, |params, key| {
+                let params: SignParams = params.parse()?;
+                Ok(sign(key, &params.payload))
+            })
+            .unwrap();
+        let handle = server.start(module).expect("Should start mocked signer");
+
+        Self {
+            _handle: handle,
+            local_address,
+        }
+    }
+
+    /// Starts a signer listening on a Unix socket at `path`.
+    pub fn start_unix_socket(key: RawKeyPair, path: &Path) {
+        let listener = UnixListener::bind(path).expect("Should bind mocked signer to a socket");
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let (reader, mut writer) = stream.into_split();
+                let mut request = String::new();
+                if BufReader::new(reader)
+                    .read_line(&mut request)
+                    .await
+                    .is_err()
+                {
+                    continue;
+                }
+
+                let request: JsonValue = serde_json::from_str(&request).unwrap_or_default();
+                let response = match serde_json::from_value::<SignParams>(request["params"].clone())
+                {
+                    Ok(params) => json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "result": sign(&key, &params.payload),
+                    }),
+                    Err(e) => json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "error": { "code": -32602, "message": e.to_string() },
+                    }),
+                };
+                let _ = writer.write_all(format!("{response}\n").as_bytes()).await;
+            }
+        });
+    }
+
+    /// Returns the HTTP address of the signer.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.local_address)
+    }
+}
+
+/// Encoded `MultiSignature` of `payload`.
+fn sign(key: &RawKeyPair, payload: &[u8]) -> Bytes {
+    Bytes(MultiSignature::from(key.sign(payload)).encode())
+}
@@ -0,0 +1,44 @@
+use subxtxt::{connection::SignedConnectionApi, Error, TxStatus};
+
+use crate::mock::{MockNode, MockStorage, RawCall};
+
+mod mock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tx_dropped_after_its_era_is_expired() {
+    let storage = MockStorage::default()
+        .with_heads((0..=20).collect())
+        .with_finalized(10)
+        .with_dropped_txs();
+    let node = MockNode::start(storage).await;
+    let connection = node.signed_connection().await;
+
+    let result = connection
+        .send_mortal_tx(RawCall, 4, TxStatus::Finalized)
+        .await;
+
+    // The era of 4 blocks starts at the finalized block 10.
+    assert!(matches!(
+        result,
+        Err(Error::TxExpired {
+            stage: TxStatus::InBlock,
+            expired_at: 14,
+            ..
+        })
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tx_dropped_within_its_era_is_not_expired() {
+    let storage = MockStorage::default()
+        .with_heads((0..=10).collect())
+        .with_dropped_txs();
+    let node = MockNode::start(storage).await;
+    let connection = node.signed_connection().await;
+
+    let result = connection
+        .send_mortal_tx(RawCall, 64, TxStatus::InBlock)
+        .await;
+
+    assert!(matches!(result, Err(Error::TxNotIncluded { .. })));
+}
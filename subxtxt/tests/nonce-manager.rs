@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use futures::future::try_join_all;
+use parity_scale_codec::{Compact, Decode};
+use subxt::{
+    ext::sp_runtime::{MultiAddress, MultiSignature},
+    tx::Era,
+};
+use subxtxt::{
+    connection::{Connection, SignedConnection, SignedConnectionApi},
+    keypair_from_string, AccountId, Error, NonceManager, TxStatus,
+};
+
+use crate::mock::{DelegatingSigner, MockNode, MockStorage, RawCall};
+
+mod mock;
+
+/// Returns the nonce of a signed, immortal extrinsic.
+fn nonce_of(extrinsic: &[u8]) -> u32 {
+    let cursor = &mut &extrinsic[..];
+    Compact::<u32>::decode(cursor).unwrap();
+    u8::decode(cursor).unwrap();
+    MultiAddress::<AccountId, u32>::decode(cursor).unwrap();
+    MultiSignature::decode(cursor).unwrap();
+    Era::decode(cursor).unwrap();
+    Compact::<u32>::decode(cursor).unwrap().0
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn concurrent_nonces_are_consecutive_and_distinct() {
+    let account = keypair_from_string("//Alice").unwrap().account_id().clone();
+    let node = MockNode::start(MockStorage::default().with_nonce(account.clone(), 5)).await;
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let nonce_manager = NonceManager::default();
+
+    let nonces = try_join_all((0..10).map(|_| nonce_manager.next_nonce(&connection, &account)))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        nonces.into_iter().collect::<HashSet<_>>(),
+        (5..15).collect::<HashSet<_>>()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resync_fetches_nonce_from_node_again() {
+    let account = keypair_from_string("//Alice").unwrap().account_id().clone();
+    let node = MockNode::start(MockStorage::default().with_nonce(account.clone(), 3)).await;
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let nonce_manager = NonceManager::default();
+
+    assert_eq!(
+        nonce_manager
+            .next_nonce(&connection, &account)
+            .await
+            .unwrap(),
+        3
+    );
+    assert_eq!(
+        nonce_manager
+            .next_nonce(&connection, &account)
+            .await
+            .unwrap(),
+        4
+    );
+
+    nonce_manager.resync().await;
+
+    assert_eq!(
+        nonce_manager
+            .next_nonce(&connection, &account)
+            .await
+            .unwrap(),
+        3
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn nonce_of_rejected_tx_is_reused() {
+    let key_pair = keypair_from_string("//Alice").unwrap();
+    let storage = MockStorage::default()
+        .with_nonce(key_pair.account_id().clone(), 7)
+        .with_rejected_txs(1);
+    let node = MockNode::start(storage.clone()).await;
+    let connection = SignedConnection::new(&node.node_address(), key_pair)
+        .await
+        .unwrap();
+
+    assert!(connection
+        .send_tx_with_nonce(RawCall, TxStatus::Submitted)
+        .await
+        .is_err());
+    connection
+        .send_tx_with_nonce(RawCall, TxStatus::Submitted)
+        .await
+        .unwrap();
+
+    let submitted = storage.submitted();
+    assert_eq!(submitted.len(), 1);
+    assert_eq!(nonce_of(&submitted[0]), 7);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn nonce_of_unsigned_tx_is_reused() {
+    let key_pair = keypair_from_string("//Alice").unwrap();
+    let storage = MockStorage::default().with_nonce(key_pair.account_id().clone(), 7);
+    let node = MockNode::start(storage.clone()).await;
+    let signer = DelegatingSigner::new(key_pair).with_failures(1);
+    let connection = SignedConnection::new(&node.node_address(), signer)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        connection
+            .send_tx_with_nonce(RawCall, TxStatus::Submitted)
+            .await,
+        Err(Error::Signer(_))
+    ));
+    connection
+        .send_tx_with_nonce(RawCall, TxStatus::Submitted)
+        .await
+        .unwrap();
+
+    assert_eq!(nonce_of(&storage.submitted()[0]), 7);
+}
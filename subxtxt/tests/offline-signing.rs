@@ -0,0 +1,69 @@
+use parity_scale_codec::{Compact, Decode};
+use subxt::{
+    ext::{
+        sp_core::{blake2_256, H256},
+        sp_runtime::{traits::Verify, MultiAddress, MultiSignature},
+    },
+    PolkadotConfig,
+};
+use subxtxt::{
+    connection::{Connection, ConnectionApi},
+    keypair_from_string, AccountId, TxStatus, UnsignedTx,
+};
+
+use crate::mock::{MockNode, MockStorage, RawCall};
+
+mod mock;
+
+#[test]
+fn offline_signed_extrinsic_carries_valid_signature() {
+    let unsigned = UnsignedTx::new::<PolkadotConfig>(
+        vec![0, 1],
+        7,
+        H256::repeat_byte(1),
+        100,
+        2,
+        Default::default(),
+    );
+    let json = serde_json::to_string(&unsigned).unwrap();
+    let unsigned: UnsignedTx = serde_json::from_str(&json).unwrap();
+
+    let key_pair = keypair_from_string("//Alice").unwrap();
+    let extrinsic = key_pair.sign_tx(&unsigned);
+
+    let cursor = &mut extrinsic.0.as_slice();
+    let Compact(length) = Compact::<u32>::decode(cursor).unwrap();
+    assert_eq!(length as usize, cursor.len());
+    assert_eq!(u8::decode(cursor).unwrap(), 0b1000_0100);
+    let address = MultiAddress::<AccountId, u32>::decode(cursor).unwrap();
+    assert_eq!(address, MultiAddress::Id(key_pair.account_id().clone()));
+    let signature = MultiSignature::decode(cursor).unwrap();
+    assert!(signature.verify(unsigned.signing_payload().as_slice(), key_pair.account_id()));
+    assert_eq!(
+        *cursor,
+        [&unsigned.extra.0[..], &unsigned.call_data.0[..]].concat()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn raw_extrinsic_is_submitted() {
+    let node = MockNode::start(MockStorage::default()).await;
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+
+    let unsigned = connection
+        .create_unsigned_tx(RawCall, 0, Default::default())
+        .await
+        .unwrap();
+    assert_eq!(unsigned.call_data.0, vec![0, 1]);
+
+    let extrinsic = keypair_from_string("//Alice").unwrap().sign_tx(&unsigned);
+    let info = connection
+        .submit_raw_extrinsic(extrinsic.0.clone())
+        .await
+        .unwrap()
+        .wait_for(TxStatus::Submitted)
+        .await
+        .unwrap();
+
+    assert_eq!(info.tx_hash, H256(blake2_256(&extrinsic.0)));
+}
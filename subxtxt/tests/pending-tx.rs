@@ -0,0 +1,66 @@
+use subxtxt::{
+    connection::{ObservedTxStatus, SignedConnectionApi},
+    Error, TxStatus,
+};
+
+use crate::mock::{MockNode, MockStorage, RawCall, TxFate, INCLUSION_DELAY};
+
+mod mock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pending_tx_is_submitted_until_included() {
+    let storage = MockStorage::default()
+        .with_heads(vec![1, 2])
+        .with_tx_fate(TxFate::Included(2));
+    let node = MockNode::start(storage.clone()).await;
+    let connection = node.signed_connection().await;
+
+    let mut pending = connection
+        .submit_tx(RawCall, Default::default())
+        .await
+        .unwrap();
+    assert_eq!(pending.status().unwrap(), TxStatus::Submitted);
+    assert_eq!(pending.block_hash(), None);
+
+    let info = pending.wait_for(TxStatus::InBlock).await.unwrap();
+    assert_eq!(info.block_hash, Some(storage.header(2).hash()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn retracted_tx_is_submitted_again() {
+    let storage = MockStorage::default()
+        .with_heads(vec![1, 2])
+        .with_tx_fate(TxFate::Retracted(2));
+    let node = MockNode::start(storage.clone()).await;
+    let connection = node.signed_connection().await;
+
+    let mut pending = connection
+        .submit_tx(RawCall, Default::default())
+        .await
+        .unwrap();
+    // Enough for the node to report both inclusion and retraction.
+    tokio::time::sleep(2 * INCLUSION_DELAY).await;
+
+    assert_eq!(pending.status().unwrap(), TxStatus::Submitted);
+    assert_eq!(
+        pending.last_observed(),
+        ObservedTxStatus::Retracted(storage.header(2).hash())
+    );
+    assert_eq!(pending.block_hash(), None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tx_no_longer_watched_is_reported_as_not_included() {
+    let node = MockNode::start(MockStorage::default().with_tx_fate(TxFate::Unwatched)).await;
+    let connection = node.signed_connection().await;
+
+    let pending = connection
+        .submit_tx(RawCall, Default::default())
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        pending.wait_for(TxStatus::InBlock).await,
+        Err(Error::TxNotIncluded { .. })
+    ));
+}
@@ -0,0 +1,75 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use futures::StreamExt;
+use subxtxt::{
+    connection::{Connection, ConnectionApi, SignedConnection, SignedConnectionApi},
+    keypair_from_string,
+    rpc::ReconnectEvent,
+};
+
+use crate::mock::{MockNode, MockStorage};
+
+mod mock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn plain_connection_does_not_survive_node_restart() {
+    let sudo = keypair_from_string("//Alice").unwrap();
+    let mut node =
+        MockNode::start(MockStorage::default().with_sudo(sudo.account_id().clone())).await;
+
+    let connection = SignedConnection::new(&node.node_address(), sudo)
+        .await
+        .unwrap();
+    node.restart().await;
+
+    assert!(connection.try_as_root().await.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reconnecting_connection_survives_node_restart() {
+    let sudo = keypair_from_string("//Alice").unwrap();
+    let mut node =
+        MockNode::start(MockStorage::default().with_sudo(sudo.account_id().clone())).await;
+
+    let reconnections = Arc::new(AtomicUsize::new(0));
+    let counter = reconnections.clone();
+    let connection = Connection::builder(&node.node_address())
+        .auto_reconnect()
+        .on_reconnect(move |event| {
+            if let ReconnectEvent::Reconnected { .. } = event {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .build()
+        .await
+        .unwrap();
+    let connection = SignedConnection::from_connection(connection, sudo);
+
+    assert!(connection.try_as_root().await.is_ok());
+    node.restart().await;
+    assert!(connection.try_as_root().await.is_ok());
+
+    assert_eq!(reconnections.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn subscription_is_renewed_after_node_restart() {
+    let mut node = MockNode::start(MockStorage::default().with_heads(vec![1, 2])).await;
+    let connection = Connection::builder(&node.node_address())
+        .auto_reconnect()
+        .build()
+        .await
+        .unwrap();
+
+    let mut blocks = connection.subscribe_finalized_blocks().await.unwrap();
+    assert_eq!(blocks.next().await.unwrap().unwrap().number, 1);
+    assert_eq!(blocks.next().await.unwrap().unwrap().number, 2);
+
+    // The renewed subscription announces all blocks again, but only the new one is streamed.
+    node.restart_with(MockStorage::default().with_heads(vec![1, 2, 3]))
+        .await;
+    assert_eq!(blocks.next().await.unwrap().unwrap().number, 3);
+}
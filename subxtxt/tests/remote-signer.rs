@@ -0,0 +1,75 @@
+use subxt::ext::sp_runtime::{traits::Verify, MultiSignature};
+use subxtxt::{
+    connection::{SignedConnection, SignedConnectionApi},
+    keypair_from_string, raw_keypair_from_string, Error, RemoteSigner, Signer, TxStatus,
+};
+
+use crate::mock::{MockNode, MockSigner, MockStorage, RawCall};
+
+mod mock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn connection_signs_with_remote_signer() {
+    let node = MockNode::start(MockStorage::default()).await;
+    let daemon = MockSigner::start_http(raw_keypair_from_string("//Alice").unwrap()).await;
+    let alice = keypair_from_string("//Alice").unwrap().account_id().clone();
+
+    let signer: RemoteSigner = RemoteSigner::http(&daemon.url(), alice.clone()).unwrap();
+    let connection = SignedConnection::new(&node.node_address(), signer)
+        .await
+        .unwrap();
+
+    connection
+        .send_tx(RawCall, TxStatus::Submitted)
+        .await
+        .unwrap();
+    assert_eq!(connection.account_id(), &alice);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn signature_of_another_account_is_rejected() {
+    let daemon = MockSigner::start_http(raw_keypair_from_string("//Bob").unwrap()).await;
+    let alice = keypair_from_string("//Alice").unwrap().account_id().clone();
+
+    let signer: RemoteSigner = RemoteSigner::http(&daemon.url(), alice).unwrap();
+    let result = signer.sign(b"payload").await;
+
+    assert!(matches!(result, Err(Error::Signer(_))));
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread")]
+async fn unix_socket_signer_returns_valid_signatures() {
+    let path = std::env::temp_dir().join(format!("subxtxt-signer-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    MockSigner::start_unix_socket(raw_keypair_from_string("//Alice").unwrap(), &path);
+    let alice = keypair_from_string("//Alice").unwrap().account_id().clone();
+
+    let signer: RemoteSigner = RemoteSigner::unix_socket(&path, alice.clone());
+    let signature: MultiSignature = signer.sign(b"payload").await.unwrap();
+
+    assert!(signature.verify(&b"payload"[..], &alice));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread")]
+async fn unresponsive_signer_times_out() {
+    let path = std::env::temp_dir().join(format!("subxtxt-silent-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let listener = tokio::net::UnixListener::bind(&path).unwrap();
+    tokio::spawn(async move {
+        let mut streams = vec![];
+        while let Ok((stream, _)) = listener.accept().await {
+            streams.push(stream);
+        }
+    });
+    let alice = keypair_from_string("//Alice").unwrap().account_id().clone();
+
+    let signer: RemoteSigner =
+        RemoteSigner::unix_socket(&path, alice).with_timeout(std::time::Duration::from_millis(200));
+    let result: Result<MultiSignature, Error> = signer.sign(b"payload").await;
+
+    assert!(matches!(result, Err(Error::Signer(_))));
+    let _ = std::fs::remove_file(&path);
+}
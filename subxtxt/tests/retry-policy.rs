@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use subxtxt::RetryPolicy;
+use tokio::time::Instant;
+
+const SECOND: Duration = Duration::from_secs(1);
+
+/// Runs an operation that always fails under `policy`, returning the number of attempts.
+async fn attempts_until_giving_up(policy: &RetryPolicy) -> u32 {
+    let mut attempts = 0;
+    let result = policy
+        .retry(|| {
+            attempts += 1;
+            async { Err::<(), _>("failure") }
+        })
+        .await;
+
+    assert_eq!(result, Err("failure"));
+    attempts
+}
+
+#[test]
+fn exponential_delays_double_up_to_the_cap() {
+    let policy = RetryPolicy::exponential(Duration::from_millis(100), SECOND).with_jitter(false);
+
+    assert_eq!(policy.delay(0), Duration::from_millis(100));
+    assert_eq!(policy.delay(1), Duration::from_millis(200));
+    assert_eq!(policy.delay(3), Duration::from_millis(800));
+    assert_eq!(policy.delay(4), SECOND);
+    // Overflowing the multiplier falls back to the cap as well.
+    assert_eq!(policy.delay(40), SECOND);
+}
+
+#[test]
+fn jittered_delays_are_between_half_and_whole_of_the_delay() {
+    let policy = RetryPolicy::exponential(Duration::from_millis(100), SECOND);
+    let exact = policy.clone().with_jitter(false);
+
+    for retry in 0..8 {
+        for _ in 0..100 {
+            let delay = policy.delay(retry);
+            assert!(delay >= exact.delay(retry) / 2 && delay <= exact.delay(retry));
+        }
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn gives_up_after_max_retries() {
+    let policy = RetryPolicy::fixed(SECOND).with_max_retries(3);
+    let start = Instant::now();
+
+    assert_eq!(attempts_until_giving_up(&policy).await, 4);
+    assert_eq!(start.elapsed(), 3 * SECOND);
+}
+
+#[tokio::test(start_paused = true)]
+async fn never_retries_with_never_policy() {
+    assert_eq!(attempts_until_giving_up(&RetryPolicy::never()).await, 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn gives_up_before_exceeding_max_elapsed() {
+    let policy = RetryPolicy::fixed(SECOND).with_max_elapsed(Duration::from_millis(2500));
+    let start = Instant::now();
+
+    // The third retry would start 3 seconds after the first attempt.
+    assert_eq!(attempts_until_giving_up(&policy).await, 3);
+    assert_eq!(start.elapsed(), 2 * SECOND);
+}
+
+#[tokio::test(start_paused = true)]
+async fn stops_on_errors_not_worth_retrying() {
+    let policy = RetryPolicy::fixed(SECOND);
+    let mut attempts = 0;
+
+    let result = policy
+        .retry_if(
+            || {
+                attempts += 1;
+                async move { Err::<(), _>(attempts) }
+            },
+            |attempt| *attempt < 2,
+        )
+        .await;
+
+    assert_eq!(result, Err(2));
+    assert_eq!(attempts, 2);
+}
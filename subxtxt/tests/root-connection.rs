@@ -0,0 +1,76 @@
+use subxt::SubstrateConfig;
+use subxtxt::{
+    connection::{ConnectionBuilder, RootConnection, SignedConnection, SignedConnectionApi},
+    keypair_from_string, Error, KeyPair,
+};
+
+use crate::mock::{MockNode, MockStorage};
+
+mod mock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn sudo_signer_gets_root_connection() {
+    let sudo = keypair_from_string("//Alice").unwrap();
+    let node = MockNode::start(MockStorage::default().with_sudo(sudo.account_id().clone())).await;
+
+    let connection = SignedConnection::new(&node.node_address(), sudo.clone())
+        .await
+        .unwrap();
+    let root = connection.try_as_root().await.unwrap();
+
+    assert_eq!(root.account_id(), sudo.account_id());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn non_sudo_signer_is_rejected() {
+    let sudo = keypair_from_string("//Alice").unwrap();
+    let signer = keypair_from_string("//Bob").unwrap();
+    let node = MockNode::start(MockStorage::default().with_sudo(sudo.account_id().clone())).await;
+
+    let connection = SignedConnection::new(&node.node_address(), signer.clone())
+        .await
+        .unwrap();
+    let error = RootConnection::try_from_connection(connection)
+        .await
+        .err()
+        .unwrap();
+
+    assert!(matches!(
+        error,
+        Error::NotSudo { account, sudo: Some(current) }
+            if &account == signer.account_id() && &current == sudo.account_id()
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn root_connection_requires_sudo_key() {
+    let signer = keypair_from_string("//Alice").unwrap();
+    let node = MockNode::start(MockStorage::default()).await;
+
+    let error = RootConnection::new(&node.node_address(), signer.clone())
+        .await
+        .err()
+        .unwrap();
+
+    assert!(matches!(
+        error,
+        Error::NotSudo { account, sudo: None } if &account == signer.account_id()
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn root_connection_works_with_custom_config() {
+    let sudo: KeyPair<SubstrateConfig> = "//Alice".parse().unwrap();
+    let node = MockNode::start(MockStorage::default().with_sudo(sudo.account_id().clone())).await;
+
+    let connection = ConnectionBuilder::<SubstrateConfig>::new(&[&node.node_address()])
+        .build()
+        .await
+        .unwrap();
+    let root = SignedConnection::from_connection(connection, sudo.clone())
+        .try_as_root()
+        .await
+        .unwrap();
+
+    assert_eq!(root.account_id(), sudo.account_id());
+}
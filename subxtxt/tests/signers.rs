@@ -0,0 +1,98 @@
+use std::sync::atomic::Ordering;
+
+use parity_scale_codec::{Compact, Decode};
+use subxt::{
+    ext::{
+        sp_core::H256,
+        sp_runtime::{traits::Verify, MultiAddress, MultiSignature},
+    },
+    PolkadotConfig,
+};
+use subxtxt::{
+    account_from_keypair,
+    connection::{SignedConnection, SignedConnectionApi},
+    ecdsa_keypair_from_string, ed25519_keypair_from_string, keypair_from_string, AccountId,
+    TxStatus, UnsignedTx,
+};
+
+use crate::mock::{DelegatingSigner, MockNode, MockStorage, RawCall};
+
+mod mock;
+
+#[test]
+fn key_pairs_of_every_scheme_sign_valid_extrinsics() {
+    let unsigned = UnsignedTx::new::<PolkadotConfig>(
+        vec![0, 1],
+        0,
+        H256::repeat_byte(1),
+        100,
+        2,
+        Default::default(),
+    );
+
+    for key_pair in [
+        keypair_from_string("//Alice").unwrap(),
+        ed25519_keypair_from_string("//Alice").unwrap(),
+        ecdsa_keypair_from_string("//Alice").unwrap(),
+    ] {
+        let extrinsic = key_pair.sign_tx(&unsigned);
+
+        let cursor = &mut extrinsic.0.as_slice();
+        Compact::<u32>::decode(cursor).unwrap();
+        u8::decode(cursor).unwrap();
+        let address = MultiAddress::<AccountId, u32>::decode(cursor).unwrap();
+        assert_eq!(address, MultiAddress::Id(key_pair.account_id().clone()));
+        let signature = MultiSignature::decode(cursor).unwrap();
+        assert!(signature.verify(unsigned.signing_payload().as_slice(), key_pair.account_id()));
+    }
+}
+
+#[test]
+fn schemes_derive_distinct_accounts() {
+    let sr25519 = keypair_from_string("//Alice").unwrap();
+    let ed25519 = ed25519_keypair_from_string("//Alice").unwrap();
+    let ecdsa = ecdsa_keypair_from_string("//Alice").unwrap();
+
+    assert_ne!(sr25519.account_id(), ed25519.account_id());
+    assert_ne!(sr25519.account_id(), ecdsa.account_id());
+    assert_ne!(ed25519.account_id(), ecdsa.account_id());
+}
+
+#[test]
+fn only_sr25519_key_pairs_have_raw_key_pair() {
+    let sr25519 = keypair_from_string("//Alice").unwrap();
+    let ed25519 = ed25519_keypair_from_string("//Alice").unwrap();
+    let ecdsa = ecdsa_keypair_from_string("//Alice").unwrap();
+
+    let raw = sr25519.raw_key_pair().unwrap();
+    assert_eq!(&account_from_keypair(raw), sr25519.account_id());
+    assert!(ed25519.raw_key_pair().is_none());
+    assert!(ecdsa.raw_key_pair().is_none());
+}
+
+#[test]
+#[allow(deprecated)]
+fn sr25519_key_pair_exposes_its_pair_signer() {
+    let key_pair = keypair_from_string("//Alice").unwrap();
+
+    assert_eq!(key_pair.pair_signer().account_id(), key_pair.account_id());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn connection_signs_with_custom_signer() {
+    let node = MockNode::start(MockStorage::default()).await;
+    let key_pair = ed25519_keypair_from_string("//Bob").unwrap();
+    let signer = DelegatingSigner::new(key_pair.clone());
+    let signed = signer.signed();
+    let connection = SignedConnection::new(&node.node_address(), signer)
+        .await
+        .unwrap();
+
+    connection
+        .send_tx(RawCall, TxStatus::Submitted)
+        .await
+        .unwrap();
+
+    assert_eq!(connection.account_id(), key_pair.account_id());
+    assert_eq!(signed.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,138 @@
+use futures::TryStreamExt;
+use subxt::{
+    ext::sp_core::twox_128,
+    metadata::DecodeStaticType,
+    storage::{
+        address::{StorageHasher, StorageMapKey, Yes},
+        StaticStorageAddress, StorageKey,
+    },
+};
+use subxtxt::{
+    connection::{Connection, ConnectionApi},
+    keypair_from_string, AccountId,
+};
+
+use crate::mock::{MockNode, MockStorage};
+
+mod mock;
+
+fn bonded(seeds: &[&str]) -> Vec<(AccountId, AccountId)> {
+    seeds
+        .iter()
+        .map(|seed| {
+            let stash = keypair_from_string(seed).unwrap().account_id().clone();
+            let controller = keypair_from_string(&format!("{seed}//controller"))
+                .unwrap()
+                .account_id()
+                .clone();
+            (stash, controller)
+        })
+        .collect()
+}
+
+async fn start_node(bonded: &[(AccountId, AccountId)]) -> MockNode {
+    let storage = bonded
+        .iter()
+        .fold(MockStorage::default(), |storage, (stash, controller)| {
+            storage.with_bonded(stash.clone(), controller.clone())
+        });
+    MockNode::start(storage).await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn storage_keys_are_fetched_in_pages() {
+    let bonded = bonded(&["//Alice", "//Bob", "//Charlie"]);
+    let node = start_node(&bonded).await;
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let prefix = [twox_128(b"Staking"), twox_128(b"Bonded")].concat();
+    let keys: Vec<StorageKey> = connection
+        .storage_keys_paged(prefix, 1, None)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(keys.len(), bonded.len());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn storage_map_entries_have_decoded_keys() {
+    let mut bonded = bonded(&["//Alice", "//Bob", "//Charlie"]);
+    let node = start_node(&bonded).await;
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let addrs: StaticStorageAddress<DecodeStaticType<AccountId>, (), (), Yes> =
+        StaticStorageAddress::new("Staking", "Bonded", vec![], [0; 32]).unvalidated();
+    let mut entries: Vec<_> = connection
+        .iter_storage_map::<AccountId, _>(&addrs, 2, None)
+        .map_ok(|entry| (entry.map_key.unwrap(), entry.value))
+        .try_collect()
+        .await
+        .unwrap();
+
+    entries.sort();
+    bonded.sort();
+    assert_eq!(entries, bonded);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn storage_entries_are_fetched_in_order() {
+    let bonded = bonded(&["//Alice", "//Bob"]);
+    let node = start_node(&bonded).await;
+    let missing = keypair_from_string("//Charlie")
+        .unwrap()
+        .account_id()
+        .clone();
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let addrs: Vec<StaticStorageAddress<DecodeStaticType<AccountId>, Yes, (), ()>> =
+        [&bonded[1].0, &missing, &bonded[0].0]
+            .into_iter()
+            .map(|stash| {
+                StaticStorageAddress::new(
+                    "Staking",
+                    "Bonded",
+                    vec![StorageMapKey::new(stash, StorageHasher::Twox64Concat)],
+                    [0; 32],
+                )
+                .unvalidated()
+            })
+            .collect();
+    let controllers = connection.get_storage_entries(&addrs, None).await.unwrap();
+
+    assert_eq!(
+        controllers,
+        vec![Some(bonded[1].1.clone()), None, Some(bonded[0].1.clone())]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn duplicate_storage_entries_are_all_fetched() {
+    let bonded = bonded(&["//Alice", "//Bob"]);
+    let node = start_node(&bonded).await;
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let addrs: Vec<StaticStorageAddress<DecodeStaticType<AccountId>, Yes, (), ()>> =
+        [&bonded[0].0, &bonded[1].0, &bonded[0].0]
+            .into_iter()
+            .map(|stash| {
+                StaticStorageAddress::new(
+                    "Staking",
+                    "Bonded",
+                    vec![StorageMapKey::new(stash, StorageHasher::Twox64Concat)],
+                    [0; 32],
+                )
+                .unvalidated()
+            })
+            .collect();
+    let controllers = connection.get_storage_entries(&addrs, None).await.unwrap();
+
+    assert_eq!(
+        controllers,
+        vec![
+            Some(bonded[0].1.clone()),
+            Some(bonded[1].1.clone()),
+            Some(bonded[0].1.clone())
+        ]
+    );
+}
@@ -0,0 +1,136 @@
+use futures::TryStreamExt;
+use subxt::{
+    metadata::DecodeStaticType,
+    storage::{
+        address::{StorageHasher, StorageMapKey, Yes},
+        StaticStorageAddress,
+    },
+};
+use subxtxt::{
+    connection::{Connection, ConnectionApi},
+    keypair_from_string, AccountId, BlockHash,
+};
+
+use crate::mock::{MockNode, MockStorage};
+
+mod mock;
+
+fn account(seed: &str) -> AccountId {
+    keypair_from_string(seed).unwrap().account_id().clone()
+}
+
+fn bonded_address(
+    stash: &AccountId,
+) -> StaticStorageAddress<DecodeStaticType<AccountId>, Yes, (), ()> {
+    StaticStorageAddress::new(
+        "Staking",
+        "Bonded",
+        vec![StorageMapKey::new(stash, StorageHasher::Twox64Concat)],
+        [0; 32],
+    )
+    .unvalidated()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn storage_subscription_starts_with_current_values() {
+    let (stash, controller, missing) = (account("//Alice"), account("//Bob"), account("//Charlie"));
+    let node =
+        MockNode::start(MockStorage::default().with_bonded(stash.clone(), controller.clone()))
+            .await;
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let mut changes = connection
+        .subscribe_storage(&[bonded_address(&stash), bonded_address(&missing)])
+        .await
+        .unwrap();
+    assert_eq!(changes.len(), 2);
+
+    let bonded = changes[0].try_next().await.unwrap();
+    let not_bonded = changes[1].try_next().await.unwrap();
+
+    assert_eq!(bonded, Some((BlockHash::zero(), Some(controller))));
+    assert_eq!(not_bonded, Some((BlockHash::zero(), None)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn all_addresses_share_a_single_subscription() {
+    let (stash, controller, missing) = (account("//Alice"), account("//Bob"), account("//Charlie"));
+    let storage = MockStorage::default().with_bonded(stash.clone(), controller);
+    let node = MockNode::start(storage.clone()).await;
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let changes = connection
+        .subscribe_storage(&[
+            bonded_address(&stash),
+            bonded_address(&missing),
+            bonded_address(&stash),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(changes.len(), 3);
+    assert_eq!(storage.storage_subscriptions(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn storage_subscription_streams_later_changes() {
+    let (stash, controller, other) = (account("//Alice"), account("//Bob"), account("//Charlie"));
+    let node = MockNode::start(
+        MockStorage::default()
+            .with_bonded(stash.clone(), controller.clone())
+            .with_bonded_change(1, other.clone(), Some(controller.clone()))
+            .with_bonded_change(2, stash.clone(), Some(other.clone())),
+    )
+    .await;
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let mut changes = connection
+        .subscribe_storage(&[bonded_address(&stash), bonded_address(&other)])
+        .await
+        .unwrap();
+
+    let storage = MockStorage::default();
+    assert_eq!(
+        changes[0].try_next().await.unwrap(),
+        Some((BlockHash::zero(), Some(controller.clone())))
+    );
+    assert_eq!(
+        changes[0].try_next().await.unwrap(),
+        Some((storage.header(2).hash(), Some(other)))
+    );
+    assert_eq!(
+        changes[1].try_next().await.unwrap(),
+        Some((BlockHash::zero(), None))
+    );
+    assert_eq!(
+        changes[1].try_next().await.unwrap(),
+        Some((storage.header(1).hash(), Some(controller)))
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn removed_value_is_streamed_as_none() {
+    let (stash, controller) = (account("//Alice"), account("//Bob"));
+    let node = MockNode::start(
+        MockStorage::default()
+            .with_bonded(stash.clone(), controller.clone())
+            .with_bonded_change(1, stash.clone(), None),
+    )
+    .await;
+
+    let connection = Connection::new(&node.node_address()).await.unwrap();
+    let mut changes = connection
+        .subscribe_storage(&[bonded_address(&stash)])
+        .await
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(
+        changes.try_next().await.unwrap(),
+        Some((BlockHash::zero(), Some(controller)))
+    );
+    assert_eq!(
+        changes.try_next().await.unwrap(),
+        Some((MockStorage::default().header(1).hash(), None))
+    );
+}
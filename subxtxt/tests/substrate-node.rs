@@ -0,0 +1,185 @@
+#![cfg(feature = "substrate-node")]
+
+use parity_scale_codec::{Compact, Encode};
+use subxt::ext::sp_runtime::MultiAddress;
+use subxtxt::{
+    keypair_from_string,
+    pallets::{
+        balances::{BalanceApi, BalanceUserApi},
+        staking::{StakingApi, StakingApiExt, StakingSudoApi, StakingUserApi},
+    },
+    substrate_node::{
+        SubstrateConnection, SubstrateNodeConfig, SubstrateRootConnection,
+        SubstrateSignedConnection,
+    },
+    Balance, KeyPair, TxStatus,
+};
+
+use crate::mock::{
+    BalanceLock, ConfigOp, MockNode, MockStorage, RewardDestination, RuntimeCall, StakingCall,
+    SudoCall, UtilityCall, BALANCES_INDEX,
+};
+
+mod mock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reads_storage_through_dynamic_api() {
+    let stash = keypair_from_string("//Alice").unwrap();
+    let controller = keypair_from_string("//Bob").unwrap();
+    let node = MockNode::start(
+        MockStorage::default()
+            .with_bonded(stash.account_id().clone(), controller.account_id().clone()),
+    )
+    .await;
+
+    let connection = SubstrateConnection::new(&node.node_address())
+        .await
+        .unwrap();
+    let bonded = connection
+        .get_bonded(stash.account_id().clone(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(bonded.as_ref(), Some(controller.account_id()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn transfer_uses_call_from_metadata() {
+    let sender: KeyPair<SubstrateNodeConfig> = "//Alice".parse().unwrap();
+    let receiver = keypair_from_string("//Bob").unwrap();
+    let storage = MockStorage::default();
+    let node = MockNode::start(storage.clone()).await;
+
+    let connection = SubstrateSignedConnection::new(&node.node_address(), sender)
+        .await
+        .unwrap();
+    connection
+        .transfer(receiver.account_id().clone(), 1_000, TxStatus::Submitted)
+        .await
+        .unwrap();
+
+    // `Balances::transfer_allow_death` to `MultiAddress::Id(receiver)`.
+    let call = [
+        vec![BALANCES_INDEX, 0, 0],
+        receiver.account_id().encode(),
+        Compact(1_000u128).encode(),
+    ]
+    .concat();
+    let submitted = storage.submitted();
+    assert_eq!(submitted.len(), 1);
+    assert!(submitted[0].0.ends_with(&call));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn locks_of_all_accounts_are_read_in_order() {
+    let alice = keypair_from_string("//Alice").unwrap().account_id().clone();
+    let bob = keypair_from_string("//Bob").unwrap().account_id().clone();
+    let lock = |id| BalanceLock {
+        id: [id; 8],
+        amount: 100,
+    };
+    let node =
+        MockNode::start(MockStorage::default().with_locks(alice.clone(), vec![lock(1), lock(2)]))
+            .await;
+
+    let connection = SubstrateConnection::new(&node.node_address())
+        .await
+        .unwrap();
+    let locks = connection
+        .locks(&[alice.clone(), bob, alice], None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        locks.iter().map(Vec::len).collect::<Vec<_>>(),
+        vec![2, 0, 2]
+    );
+}
+
+/// `Staking::bond` of `value`, without a controller, as in the metadata of the mocked node.
+fn bond_call(value: Balance) -> RuntimeCall {
+    RuntimeCall::Staking(StakingCall::bond {
+        value,
+        payee: RewardDestination::Staked,
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bond_uses_call_shape_from_metadata() {
+    let stash: KeyPair<SubstrateNodeConfig> = "//Alice".parse().unwrap();
+    let controller = keypair_from_string("//Bob").unwrap();
+    let storage = MockStorage::default();
+    let node = MockNode::start(storage.clone()).await;
+
+    let connection = SubstrateSignedConnection::new(&node.node_address(), stash)
+        .await
+        .unwrap();
+    connection
+        .bond(1_000, controller.account_id().clone(), TxStatus::Submitted)
+        .await
+        .unwrap();
+
+    let submitted = storage.submitted();
+    assert_eq!(submitted.len(), 1);
+    assert!(submitted[0].0.ends_with(&bond_call(1_000).encode()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn batch_bond_uses_call_shape_from_metadata() {
+    let sudo: KeyPair<SubstrateNodeConfig> = "//Alice".parse().unwrap();
+    let stash = keypair_from_string("//Bob").unwrap().account_id().clone();
+    let controller = keypair_from_string("//Charlie")
+        .unwrap()
+        .account_id()
+        .clone();
+    let storage = MockStorage::default().with_sudo(sudo.account_id().clone());
+    let node = MockNode::start(storage.clone()).await;
+
+    let connection = SubstrateRootConnection::new(&node.node_address(), sudo)
+        .await
+        .unwrap();
+    connection
+        .batch_bond(&[(stash.clone(), controller)], 1_000, TxStatus::Submitted)
+        .await
+        .unwrap();
+
+    let call = RuntimeCall::Utility(UtilityCall::batch {
+        calls: vec![RuntimeCall::Sudo(SudoCall::sudo_as {
+            who: MultiAddress::Id(stash.into()),
+            call: Box::new(bond_call(1_000)),
+        })],
+    });
+    let submitted = storage.submitted();
+    assert_eq!(submitted.len(), 1);
+    assert!(submitted[0].0.ends_with(&call.encode()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn staking_config_covers_all_options_from_metadata() {
+    let sudo: KeyPair<SubstrateNodeConfig> = "//Alice".parse().unwrap();
+    let storage = MockStorage::default().with_sudo(sudo.account_id().clone());
+    let node = MockNode::start(storage.clone()).await;
+
+    let connection = SubstrateRootConnection::new(&node.node_address(), sudo)
+        .await
+        .unwrap();
+    connection
+        .set_staking_config(Some(10), None, Some(5), None, TxStatus::Submitted)
+        .await
+        .unwrap();
+
+    let call = RuntimeCall::Sudo(SudoCall::sudo {
+        call: Box::new(RuntimeCall::Staking(StakingCall::set_staking_configs {
+            min_nominator_bond: ConfigOp::Set(10),
+            min_validator_bond: ConfigOp::Noop,
+            max_nominator_count: ConfigOp::Set(5),
+            max_validator_count: ConfigOp::Noop,
+            chill_threshold: ConfigOp::Noop,
+            min_commission: ConfigOp::Noop,
+            max_staked_rewards: ConfigOp::Noop,
+        })),
+    });
+    let submitted = storage.submitted();
+    assert_eq!(submitted.len(), 1);
+    assert!(submitted[0].0.ends_with(&call.encode()));
+}
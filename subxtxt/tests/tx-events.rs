@@ -0,0 +1,66 @@
+use parity_scale_codec::Decode;
+use subxt::events::StaticEvent;
+use subxtxt::{
+    connection::{SignedConnection, SignedConnectionApi},
+    keypair_from_string, TxStatus,
+};
+
+use crate::mock::{MockNode, MockStorage, RawCall, StakingEvent, TxFate};
+
+mod mock;
+
+#[derive(Debug, Eq, PartialEq, Decode)]
+struct Rewarded {
+    stash: [u8; 32],
+    amount: u128,
+}
+
+impl StaticEvent for Rewarded {
+    const PALLET: &'static str = "Staking";
+    const EVENT: &'static str = "Rewarded";
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tx_result_has_events_block_number_and_extrinsic_index() {
+    let storage = MockStorage::default()
+        .with_heads(vec![1, 2, 3])
+        .with_tx_fate(TxFate::Included(3))
+        // Emitted by the inherent, so it must not be attributed to the transaction.
+        .with_event(
+            3,
+            StakingEvent::Rewarded {
+                stash: [1; 32],
+                amount: 100,
+            },
+        )
+        .with_tx_event(StakingEvent::Rewarded {
+            stash: [2; 32],
+            amount: 200,
+        });
+    let node = MockNode::start(storage.clone()).await;
+    let connection = SignedConnection::new(
+        &node.node_address(),
+        keypair_from_string("//Alice").unwrap(),
+    )
+    .await
+    .unwrap();
+
+    let result = connection
+        .send_tx_with_events(RawCall, TxStatus::InBlock)
+        .await
+        .unwrap();
+
+    assert_eq!(result.block_number, 3);
+    assert_eq!(result.block_hash, storage.header(3).hash());
+    // The first extrinsic of the block is the inherent.
+    assert_eq!(result.extrinsic_index, 1);
+    assert_eq!(
+        result.find::<Rewarded>().unwrap(),
+        vec![Rewarded {
+            stash: [2; 32],
+            amount: 200,
+        }]
+    );
+    assert!(result.has::<Rewarded>().unwrap());
+    assert!(format!("{result:?}").contains("Staking::Rewarded"));
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use subxtxt::{
+    connection::{ObservedTxStatus, SignedConnectionApi, TxTimeouts},
+    Error, TxStatus,
+};
+
+use crate::mock::{MockNode, MockStorage, RawCall, TxFate, INCLUSION_DELAY};
+
+mod mock;
+
+/// Shorter than [`INCLUSION_DELAY`].
+const SHORT: Duration = Duration::from_millis(50);
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tx_stuck_in_pool_times_out_waiting_for_block() {
+    let node = MockNode::start(MockStorage::default().with_tx_fate(TxFate::Pending)).await;
+    let timeouts = TxTimeouts {
+        in_block: Some(SHORT),
+        finalized: None,
+    };
+    let connection = node
+        .signed_connection_with(|builder| builder.tx_timeouts(timeouts))
+        .await;
+
+    let result = connection.send_tx(RawCall, TxStatus::Finalized).await;
+
+    assert!(matches!(
+        result,
+        Err(Error::TxTimeout {
+            stage: TxStatus::InBlock,
+            last_status: ObservedTxStatus::Ready,
+            ..
+        })
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn finalization_timeout_counts_from_inclusion() {
+    let storage = MockStorage::default()
+        .with_heads(vec![1, 2])
+        .with_tx_fate(TxFate::InBlock(2));
+    let node = MockNode::start(storage.clone()).await;
+    // Inclusion takes longer than the finalization timeout, which must not be exceeded by it.
+    let timeouts = TxTimeouts {
+        in_block: Some(4 * INCLUSION_DELAY),
+        finalized: Some(SHORT),
+    };
+    let connection = node
+        .signed_connection_with(|builder| builder.tx_timeouts(timeouts))
+        .await;
+
+    let result = connection.send_tx(RawCall, TxStatus::Finalized).await;
+
+    match result {
+        Err(Error::TxTimeout {
+            stage: TxStatus::Finalized,
+            last_status: ObservedTxStatus::InBlock(hash),
+            ..
+        }) => assert_eq!(hash, storage.header(2).hash()),
+        result => panic!("Expected timeout of finalization, got {result:?}"),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn per_call_timeouts_override_connection_ones() {
+    let storage = MockStorage::default()
+        .with_heads(vec![1, 2])
+        .with_tx_fate(TxFate::Included(2));
+    let node = MockNode::start(storage).await;
+    let timeouts = TxTimeouts {
+        in_block: Some(SHORT),
+        finalized: Some(SHORT),
+    };
+    let connection = node
+        .signed_connection_with(|builder| builder.tx_timeouts(timeouts))
+        .await;
+
+    let info = connection
+        .submit_tx(RawCall, Default::default())
+        .await
+        .unwrap()
+        .with_timeouts(TxTimeouts::default())
+        .wait_for(TxStatus::Finalized)
+        .await
+        .unwrap();
+
+    assert!(info.block_hash.is_some());
+}